@@ -0,0 +1,293 @@
+use std::{
+    fmt,
+    hint::black_box,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{input::InputSource, ParseError, Part, Solution};
+
+use crate::days;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub warmup: usize,
+    pub runs: usize,
+    pub parts: Vec<Part>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => f.pad("parse"),
+            Stage::Part(Part::One) => f.pad("part1"),
+            Stage::Part(Part::Two) => f.pad("part2"),
+        }
+    }
+}
+
+impl FromStr for Stage {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Stage::Parse),
+            "part1" => Ok(Stage::Part(Part::One)),
+            "part2" => Ok(Stage::Part(Part::Two)),
+            other => Err(format!("Unknown stage `{other}`")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub mean: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let runs = samples.len();
+        if runs == 0 {
+            return Stats {
+                runs,
+                mean: Duration::ZERO,
+                median: Duration::ZERO,
+                stddev: Duration::ZERO,
+            };
+        }
+        let nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / runs as f64;
+
+        let mut sorted = nanos.clone();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        let median = if runs.is_multiple_of(2) {
+            (sorted[runs / 2 - 1] + sorted[runs / 2]) / 2.0
+        } else {
+            sorted[runs / 2]
+        };
+
+        let variance = if runs > 1 {
+            nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / (runs - 1) as f64
+        } else {
+            0.0
+        };
+
+        Stats {
+            runs,
+            mean: Duration::from_nanos(mean.round() as u64),
+            median: Duration::from_nanos(median.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Measurement {
+    pub day: u8,
+    pub stage: Stage,
+    pub stats: Stats,
+}
+
+/// Times parsing and each requested part of one day separately.
+pub fn measure<S: Solution>(
+    input: &str,
+    config: &Config,
+) -> Result<Vec<(Stage, Stats)>, ParseError> {
+    let parsed = S::parse(input)?;
+    let mut stages = vec![(Stage::Parse, sample(config, || S::parse(input)))];
+    for part in config.parts.iter() {
+        let stats = match part {
+            Part::One => sample(config, || S::part_one(&parsed)),
+            Part::Two => sample(config, || S::part_two(&parsed)),
+        };
+        stages.push((Stage::Part(*part), stats));
+    }
+    Ok(stages)
+}
+
+fn sample<T>(config: &Config, mut run: impl FnMut() -> T) -> Stats {
+    for _ in 0..config.warmup {
+        black_box(run());
+    }
+    let samples: Vec<Duration> = (0..config.runs)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(run());
+            start.elapsed()
+        })
+        .collect();
+    Stats::from_samples(&samples)
+}
+
+/// Benchmarks each day on its default input, printing a row per stage as soon as it is
+/// measured. With a baseline, rows whose mean got slower by more than `threshold` (a
+/// fraction, `0.1` for 10%) are flagged and counted as regressions.
+pub fn run(
+    numbers: &[u8],
+    config: &Config,
+    baseline: Option<&[Measurement]>,
+    threshold: f64,
+) -> Result<(Vec<Measurement>, usize), String> {
+    println!(
+        "{:>3}  {:<5}  {:>5}  {:>12}  {:>12}  {:>12}  {:>9}",
+        "Day", "Stage", "Runs", "Mean", "Median", "Stddev", "Change"
+    );
+    let mut measurements = vec![];
+    let mut regressions = 0;
+    for number in numbers {
+        let day = days::find(*number).ok_or(format!("Day {number} is not solved yet"))?;
+        let source = InputSource::default_for(day.number);
+        let input = source.read().map_err(|err| err.to_string())?;
+        let stages = day
+            .bench(&input, config)
+            .map_err(|err| format!("Failed to parse `{source}`: {err}"))?;
+
+        for (stage, stats) in stages {
+            let previous = baseline.and_then(|baseline| {
+                baseline
+                    .iter()
+                    .find(|m| m.day == day.number && m.stage == stage)
+            });
+            let change = previous.map(|previous| change(&previous.stats, &stats));
+            let flag = match change {
+                Some(change) if change > threshold => {
+                    regressions += 1;
+                    "  REGRESSION"
+                }
+                _ => "",
+            };
+            println!(
+                "{:>3}  {:<5}  {:>5}  {:>12}  {:>12}  {:>12}  {:>9}{}",
+                day.number,
+                stage,
+                stats.runs,
+                format_duration(stats.mean),
+                format_duration(stats.median),
+                format_duration(stats.stddev),
+                change.map_or(String::from("-"), |change| format!(
+                    "{:+.1}%",
+                    change * 100.0
+                )),
+                flag
+            );
+            measurements.push(Measurement {
+                day: day.number,
+                stage,
+                stats,
+            });
+        }
+    }
+    Ok((measurements, regressions))
+}
+
+/// Saved results: a header followed by one tab separated line per day and stage, with
+/// durations in nanoseconds.
+pub fn to_tsv(measurements: &[Measurement]) -> String {
+    let mut tsv = String::from("day\tstage\truns\tmean_ns\tmedian_ns\tstddev_ns\n");
+    for m in measurements {
+        tsv.push_str(&format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\n",
+            m.day,
+            m.stage,
+            m.stats.runs,
+            m.stats.mean.as_nanos(),
+            m.stats.median.as_nanos(),
+            m.stats.stddev.as_nanos()
+        ));
+    }
+    tsv
+}
+
+pub fn from_tsv(tsv: &str) -> Result<Vec<Measurement>, String> {
+    tsv.lines()
+        .enumerate()
+        .skip(1)
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| {
+            let invalid = || format!("Benchmark line {}: invalid entry `{line}`", idx + 1);
+            let fields: Vec<&str> = line.split('\t').collect();
+            let [day, stage, runs, mean, median, stddev] = fields[..] else {
+                return Err(invalid());
+            };
+            let nanos = |field: &str| field.parse().map(Duration::from_nanos);
+            Ok(Measurement {
+                day: day.parse().map_err(|_| invalid())?,
+                stage: stage.parse()?,
+                stats: Stats {
+                    runs: runs.parse().map_err(|_| invalid())?,
+                    mean: nanos(mean).map_err(|_| invalid())?,
+                    median: nanos(median).map_err(|_| invalid())?,
+                    stddev: nanos(stddev).map_err(|_| invalid())?,
+                },
+            })
+        })
+        .collect()
+}
+
+/// Relative change of the mean against a baseline, e.g. `0.25` for 25% slower.
+pub fn change(baseline: &Stats, current: &Stats) -> f64 {
+    if baseline.mean.is_zero() {
+        return 0.0;
+    }
+    current.mean.as_secs_f64() / baseline.mean.as_secs_f64() - 1.0
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    format!("{duration:.2?}")
+}
+
+#[cfg(test)]
+mod bench_tests {
+    use crate::bench::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|v| Duration::from_millis(*v)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&millis(&[4, 2, 6, 8]));
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.mean, Duration::from_millis(5));
+        assert_eq!(stats.median, Duration::from_millis(5));
+        assert_eq!(stats.stddev.as_micros(), 2581);
+
+        let stats = Stats::from_samples(&millis(&[3]));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_tsv_round_trip() {
+        let measurements = vec![
+            Measurement {
+                day: 7,
+                stage: Stage::Parse,
+                stats: Stats::from_samples(&millis(&[1, 2, 3])),
+            },
+            Measurement {
+                day: 7,
+                stage: Stage::Part(Part::Two),
+                stats: Stats::from_samples(&millis(&[10])),
+            },
+        ];
+        assert_eq!(from_tsv(&to_tsv(&measurements)), Ok(measurements));
+        assert!(from_tsv("header\n7\tparse\t1").is_err());
+    }
+
+    #[test]
+    fn test_change() {
+        let baseline = Stats::from_samples(&millis(&[10]));
+        let current = Stats::from_samples(&millis(&[15]));
+        assert!((change(&baseline, &current) - 0.5).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,88 @@
+use aoc_common::{Answer, ParseError, Part, Solution};
+
+use crate::bench::{self, Config, Stage, Stats};
+
+type Timings = Vec<(Stage, Stats)>;
+
+pub struct Day {
+    pub number: u8,
+    solve: fn(&str, Part) -> Result<Answer, ParseError>,
+    bench: fn(&str, &Config) -> Result<Timings, ParseError>,
+}
+
+impl Day {
+    pub fn solve(&self, input: &str, part: Part) -> Result<Answer, ParseError> {
+        (self.solve)(input, part)
+    }
+
+    pub fn bench(&self, input: &str, config: &Config) -> Result<Timings, ParseError> {
+        (self.bench)(input, config)
+    }
+}
+
+pub static DAYS: [Day; 12] = [
+    Day {
+        number: day_1::Day1::DAY,
+        solve: day_1::Day1::solve,
+        bench: bench::measure::<day_1::Day1>,
+    },
+    Day {
+        number: day_2::Day2::DAY,
+        solve: day_2::Day2::solve,
+        bench: bench::measure::<day_2::Day2>,
+    },
+    Day {
+        number: day_3::Day3::DAY,
+        solve: day_3::Day3::solve,
+        bench: bench::measure::<day_3::Day3>,
+    },
+    Day {
+        number: day_4::Day4::DAY,
+        solve: day_4::Day4::solve,
+        bench: bench::measure::<day_4::Day4>,
+    },
+    Day {
+        number: day_5::Day5::DAY,
+        solve: day_5::Day5::solve,
+        bench: bench::measure::<day_5::Day5>,
+    },
+    Day {
+        number: day_6::Day6::DAY,
+        solve: day_6::Day6::solve,
+        bench: bench::measure::<day_6::Day6>,
+    },
+    Day {
+        number: day_7::Day7::DAY,
+        solve: day_7::Day7::solve,
+        bench: bench::measure::<day_7::Day7>,
+    },
+    Day {
+        number: day_8::Day8::DAY,
+        solve: day_8::Day8::solve,
+        bench: bench::measure::<day_8::Day8>,
+    },
+    Day {
+        number: day_9::Day9::DAY,
+        solve: day_9::Day9::solve,
+        bench: bench::measure::<day_9::Day9>,
+    },
+    Day {
+        number: day_10::Day10::DAY,
+        solve: day_10::Day10::solve,
+        bench: bench::measure::<day_10::Day10>,
+    },
+    Day {
+        number: day_11::Day11::DAY,
+        solve: day_11::Day11::solve,
+        bench: bench::measure::<day_11::Day11>,
+    },
+    Day {
+        number: day_12::Day12::DAY,
+        solve: day_12::Day12::solve,
+        bench: bench::measure::<day_12::Day12>,
+    },
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
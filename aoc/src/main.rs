@@ -0,0 +1,365 @@
+mod bench;
+mod days;
+mod manifest;
+mod output;
+mod scaffold;
+mod verify;
+
+use std::{fs, path::Path, process, str::FromStr, time::Instant};
+
+use aoc_common::{
+    cli::Args,
+    input::{workspace_path, InputSource},
+    Part,
+};
+use days::DAYS;
+use manifest::{Manifest, DEFAULT_MANIFEST};
+use output::{Format, Record};
+
+const USAGE: &str = "Usage:
+    aoc run --day <N> [--part <1|2>] [--input <path>|-] [--format <text|json|csv>]
+    aoc run --all [--part <1|2>] [--format <text|json|csv>]
+    aoc verify [--day <N>] [--manifest <path>]
+    aoc bench [--day <N>] [--part <1|2>] [--runs <N>] [--warmup <N>]
+              [--save <path>] [--baseline <path>] [--threshold <percent>]
+    aoc new-day --day <N>";
+
+#[derive(Debug, PartialEq)]
+enum Command {
+    Run(RunOptions),
+    Verify(VerifyOptions),
+    Bench(BenchOptions),
+    NewDay(u8),
+}
+
+#[derive(Debug, PartialEq)]
+struct RunOptions {
+    days: Vec<u8>,
+    parts: Vec<Part>,
+    input: Option<String>,
+    format: Format,
+}
+
+#[derive(Debug, PartialEq)]
+struct VerifyOptions {
+    days: Vec<u8>,
+    manifest: String,
+}
+
+#[derive(Debug, PartialEq)]
+struct BenchOptions {
+    days: Vec<u8>,
+    config: bench::Config,
+    save: Option<String>,
+    baseline: Option<String>,
+    threshold: f64,
+}
+
+fn main() {
+    let command = parse_args(Args::from_env()).unwrap_or_else(|err| {
+        eprintln!("Error: {err}");
+        eprintln!("{USAGE}");
+        process::exit(1);
+    });
+    let result = match command {
+        Command::Run(options) => run(options),
+        Command::Verify(options) => verify(options),
+        Command::Bench(options) => run_bench(options),
+        Command::NewDay(day) => new_day(day),
+    };
+    if let Err(err) = result {
+        eprintln!("Error: {err}");
+        process::exit(1);
+    }
+}
+
+fn run(options: RunOptions) -> Result<(), String> {
+    if let Some(header) = options.format.header() {
+        println!("{header}");
+    }
+    for number in options.days.iter() {
+        let day = days::find(*number).ok_or(format!("Day {number} is not solved yet"))?;
+        let source = InputSource::from_arg(options.input.as_deref(), day.number);
+        let input = source.read().map_err(|err| err.to_string())?;
+        for part in options.parts.iter() {
+            let start = Instant::now();
+            let answer = day
+                .solve(&input, *part)
+                .map_err(|err| format!("Failed to parse `{source}`: {err}"))?;
+            let record = Record {
+                day: day.number,
+                part: *part,
+                answer,
+                elapsed: start.elapsed(),
+                input: source.to_string(),
+            };
+            println!("{}", options.format.render(&record));
+        }
+    }
+    Ok(())
+}
+
+fn verify(options: VerifyOptions) -> Result<(), String> {
+    let path = workspace_path(&options.manifest);
+    let manifest = InputSource::File(path)
+        .read()
+        .map_err(|err| err.to_string())
+        .and_then(|text| Manifest::from_str(&text))?;
+    if verify::verify(&manifest, &options.days) {
+        Ok(())
+    } else {
+        Err(String::from("Some answers don't match the manifest"))
+    }
+}
+
+fn run_bench(options: BenchOptions) -> Result<(), String> {
+    let baseline = match &options.baseline {
+        Some(path) => {
+            let tsv = InputSource::File(path.into())
+                .read()
+                .map_err(|err| err.to_string())?;
+            Some(bench::from_tsv(&tsv)?)
+        }
+        None => None,
+    };
+    let (measurements, regressions) = bench::run(
+        &options.days,
+        &options.config,
+        baseline.as_deref(),
+        options.threshold / 100.0,
+    )?;
+    if let Some(path) = &options.save {
+        fs::write(path, bench::to_tsv(&measurements))
+            .map_err(|err| format!("Failed to write `{path}`: {err}"))?;
+    }
+    if regressions > 0 {
+        return Err(format!(
+            "{regressions} stages are more than {}% slower than the baseline",
+            options.threshold
+        ));
+    }
+    Ok(())
+}
+
+fn new_day(day: u8) -> Result<(), String> {
+    let manifest = workspace_path("Cargo.toml");
+    let root = manifest.parent().unwrap_or(Path::new(""));
+    for path in scaffold::new_day(root, day)? {
+        println!("Wrote {}", path.display());
+    }
+    println!("Add day_{day} to aoc/Cargo.toml and aoc/src/days.rs to run it through aoc");
+    Ok(())
+}
+
+fn parse_args(mut args: Args) -> Result<Command, String> {
+    match args.subcommand().as_deref() {
+        Some("run") => parse_run_args(args).map(Command::Run),
+        Some("verify") => parse_verify_args(args).map(Command::Verify),
+        Some("bench") => parse_bench_args(args).map(Command::Bench),
+        Some("new-day") => parse_new_day_args(args).map(Command::NewDay),
+        Some(command) => Err(format!("Unknown command `{command}`")),
+        None => Err(String::from("Missing command")),
+    }
+}
+
+fn parse_day(args: &mut Args) -> Result<Option<u8>, String> {
+    match args.value("--day")? {
+        Some(value) => value
+            .parse::<u8>()
+            .map(Some)
+            .map_err(|_| format!("Invalid day `{value}`")),
+        None => Ok(None),
+    }
+}
+
+fn parse_number<T: FromStr>(args: &mut Args, name: &str, default: T) -> Result<T, String> {
+    match args.value(name)? {
+        Some(value) => value
+            .parse()
+            .map_err(|_| format!("Invalid value `{value}` for {name}")),
+        None => Ok(default),
+    }
+}
+
+fn parse_part(args: &mut Args) -> Result<Vec<Part>, String> {
+    match args.value("--part")? {
+        Some(value) => Ok(vec![value.parse()?]),
+        None => Ok(vec![Part::One, Part::Two]),
+    }
+}
+
+fn parse_bench_args(mut args: Args) -> Result<BenchOptions, String> {
+    let day = parse_day(&mut args)?;
+    let parts = parse_part(&mut args)?;
+    let runs = parse_number(&mut args, "--runs", 10)?;
+    let warmup = parse_number(&mut args, "--warmup", 1)?;
+    let threshold = parse_number(&mut args, "--threshold", 10.0)?;
+    let save = args.value("--save")?;
+    let baseline = args.value("--baseline")?;
+    args.finish()?;
+
+    if runs == 0 {
+        return Err(String::from("--runs must be at least 1"));
+    }
+    let days = match day {
+        Some(day) => vec![day],
+        None => DAYS.iter().map(|day| day.number).collect(),
+    };
+    Ok(BenchOptions {
+        days,
+        config: bench::Config {
+            warmup,
+            runs,
+            parts,
+        },
+        save,
+        baseline,
+        threshold,
+    })
+}
+
+fn parse_verify_args(mut args: Args) -> Result<VerifyOptions, String> {
+    let day = parse_day(&mut args)?;
+    let manifest = args
+        .value("--manifest")?
+        .unwrap_or_else(|| String::from(DEFAULT_MANIFEST));
+    args.finish()?;
+
+    let days = match day {
+        Some(day) => vec![day],
+        None => DAYS.iter().map(|day| day.number).collect(),
+    };
+    Ok(VerifyOptions { days, manifest })
+}
+
+fn parse_new_day_args(mut args: Args) -> Result<u8, String> {
+    let day = parse_day(&mut args)?.ok_or("Expected --day <N>")?;
+    args.finish()?;
+
+    if !(1..=25).contains(&day) {
+        return Err(format!(
+            "Day {day} is outside the 1-25 range of the calendar"
+        ));
+    }
+    Ok(day)
+}
+
+fn parse_run_args(mut args: Args) -> Result<RunOptions, String> {
+    let day = parse_day(&mut args)?;
+    let parts = parse_part(&mut args)?;
+    let input = args.value("--input")?;
+    let all = args.flag("--all");
+    let format = match args.value("--format")? {
+        Some(value) => value.parse()?,
+        None => Format::Text,
+    };
+    args.finish()?;
+
+    let days = match (day, all) {
+        (Some(_), true) => return Err(String::from("--day and --all can't be used together")),
+        (Some(day), false) => vec![day],
+        (None, true) if input.is_some() => {
+            return Err(String::from("--input can only be used with a single --day"))
+        }
+        (None, true) => DAYS.iter().map(|day| day.number).collect(),
+        (None, false) => return Err(String::from("Expected either --day <N> or --all")),
+    };
+    Ok(RunOptions {
+        days,
+        parts,
+        input,
+        format,
+    })
+}
+
+#[cfg(test)]
+mod aoc_tests {
+    use crate::*;
+
+    fn args(line: &str) -> Args {
+        Args::new(line.split_whitespace().map(String::from).collect())
+    }
+
+    #[test]
+    fn test_parse_single_part() {
+        assert_eq!(
+            parse_args(args("run --day 7 --part 2 --input - --format csv")),
+            Ok(Command::Run(RunOptions {
+                days: vec![7],
+                parts: vec![Part::Two],
+                input: Some(String::from("-")),
+                format: Format::Csv,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_all() {
+        let Ok(Command::Run(options)) = parse_args(args("run --all")) else {
+            panic!("Expected a run command");
+        };
+        assert_eq!(options.days, (1..=12).collect::<Vec<u8>>());
+        assert_eq!(options.parts, vec![Part::One, Part::Two]);
+        assert_eq!(options.input, None);
+        assert_eq!(options.format, Format::Text);
+    }
+
+    #[test]
+    fn test_parse_verify() {
+        assert_eq!(
+            parse_args(args("verify --day 3 --manifest other")),
+            Ok(Command::Verify(VerifyOptions {
+                days: vec![3],
+                manifest: String::from("other"),
+            }))
+        );
+        let Ok(Command::Verify(options)) = parse_args(args("verify")) else {
+            panic!("Expected a verify command");
+        };
+        assert_eq!(options.manifest, DEFAULT_MANIFEST);
+        assert_eq!(options.days.len(), 12);
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        let Ok(Command::Bench(options)) =
+            parse_args(args("bench --day 5 --part 1 --runs 3 --save out.tsv"))
+        else {
+            panic!("Expected a bench command");
+        };
+        assert_eq!(options.days, vec![5]);
+        assert_eq!(
+            options.config,
+            bench::Config {
+                warmup: 1,
+                runs: 3,
+                parts: vec![Part::One],
+            }
+        );
+        assert_eq!(options.save.as_deref(), Some("out.tsv"));
+        assert_eq!(options.baseline, None);
+        assert!(parse_args(args("bench --runs 0")).is_err());
+        assert!(parse_args(args("bench --threshold fast")).is_err());
+    }
+
+    #[test]
+    fn test_parse_new_day() {
+        assert_eq!(
+            parse_args(args("new-day --day 13")),
+            Ok(Command::NewDay(13))
+        );
+        assert!(parse_args(args("new-day")).is_err());
+        assert!(parse_args(args("new-day --day 26")).is_err());
+    }
+
+    #[test]
+    fn test_parse_rejects_day_and_all() {
+        assert!(parse_args(args("run --day 1 --all")).is_err());
+        assert!(parse_args(args("run --all --input day_1/example")).is_err());
+        assert!(parse_args(args("run")).is_err());
+        assert!(parse_args(args("check")).is_err());
+        assert!(parse_args(args("run --part 3 --day 1")).is_err());
+        assert!(parse_args(args("run --day 1 --verbose")).is_err());
+        assert!(parse_args(args("run --day 1 --format xml")).is_err());
+    }
+}
@@ -0,0 +1,92 @@
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use aoc_common::Part;
+
+pub const DEFAULT_MANIFEST: &str = "answers";
+
+/// A known-good answer for one part of a day, run against one input file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub day: u8,
+    pub part: Part,
+    pub input: PathBuf,
+    pub answer: String,
+}
+
+/// The checked-in `answers` file: one `day part input answer` entry per line, with blank
+/// lines and `#` comments ignored.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Manifest {
+    pub entries: Vec<Expected>,
+}
+
+impl FromStr for Manifest {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut entries = vec![];
+        for (idx, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [day, part, input, answer] = fields[..] else {
+                return Err(format!(
+                    "Manifest line {}: expected `day part input answer`, found `{line}`",
+                    idx + 1
+                ));
+            };
+            let day = day
+                .parse()
+                .map_err(|_| format!("Manifest line {}: invalid day `{day}`", idx + 1))?;
+            let part = part
+                .parse()
+                .map_err(|err| format!("Manifest line {}: {err}", idx + 1))?;
+            entries.push(Expected {
+                day,
+                part,
+                input: PathBuf::from(input),
+                answer: answer.to_owned(),
+            });
+        }
+        Ok(Manifest { entries })
+    }
+}
+
+impl Manifest {
+    pub fn get(&self, day: u8, part: Part, input: &Path) -> Option<&Expected> {
+        self.entries
+            .iter()
+            .find(|entry| entry.day == day && entry.part == part && entry.input == input)
+    }
+}
+
+#[cfg(test)]
+mod manifest_tests {
+    use crate::manifest::*;
+
+    #[test]
+    fn test_parse_manifest() {
+        let manifest = Manifest::from_str("# comment\n\n7 2 day_7/example 5905\n").unwrap();
+        assert_eq!(
+            manifest.get(7, Part::Two, Path::new("day_7/example")),
+            Some(&Expected {
+                day: 7,
+                part: Part::Two,
+                input: PathBuf::from("day_7/example"),
+                answer: String::from("5905"),
+            })
+        );
+        assert_eq!(manifest.get(7, Part::One, Path::new("day_7/example")), None);
+    }
+
+    #[test]
+    fn test_parse_manifest_errors() {
+        assert!(Manifest::from_str("7 2 day_7/example").is_err());
+        assert!(Manifest::from_str("7 3 day_7/example 5905").is_err());
+    }
+}
@@ -0,0 +1,152 @@
+use std::{str::FromStr, time::Duration};
+
+use aoc_common::{json, Answer, Part};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            other => Err(format!(
+                "Unknown format `{other}`, expected text, json or csv"
+            )),
+        }
+    }
+}
+
+/// One solved part, as reported by `aoc run`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+    pub input: String,
+}
+
+impl Format {
+    pub fn header(&self) -> Option<&'static str> {
+        match self {
+            Format::Csv => Some("day,part,answer,answer_type,elapsed_us,input"),
+            Format::Text | Format::Json => None,
+        }
+    }
+
+    /// Renders a record as a text line, a JSON object on its own line or a CSV row.
+    pub fn render(&self, record: &Record) -> String {
+        match self {
+            Format::Text => format!(
+                "Day {} Part {}: {}",
+                record.day, record.part, record.answer
+            ),
+            Format::Json => format!(
+                "{{\"day\":{},\"part\":{},\"answer\":{},\"answer_type\":{},\"elapsed_us\":{},\"input\":{}}}",
+                record.day,
+                record.part,
+                json_answer(record.answer),
+                json::string(record.answer.kind()),
+                record.elapsed.as_micros(),
+                json::string(&record.input)
+            ),
+            Format::Csv => format!(
+                "{},{},{},{},{},{}",
+                record.day,
+                record.part,
+                csv_answer(record.answer),
+                record.answer.kind(),
+                record.elapsed.as_micros(),
+                csv_field(&record.input)
+            ),
+        }
+    }
+}
+
+/// The answer as a JSON number, or `null` when the part isn't solved.
+fn json_answer(answer: Answer) -> String {
+    match answer {
+        Answer::Unsolved => String::from("null"),
+        answer => answer.to_string(),
+    }
+}
+
+/// The answer as a CSV field, left empty when the part isn't solved.
+fn csv_answer(answer: Answer) -> String {
+    match answer {
+        Answer::Unsolved => String::new(),
+        answer => answer.to_string(),
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod output_tests {
+    use crate::output::*;
+
+    fn record() -> Record {
+        Record {
+            day: 9,
+            part: Part::Two,
+            answer: Answer::Signed(-2),
+            elapsed: Duration::from_micros(1500),
+            input: String::from("inputs/day,9"),
+        }
+    }
+
+    #[test]
+    fn test_render_text() {
+        assert_eq!(Format::Text.render(&record()), "Day 9 Part 2: -2");
+    }
+
+    #[test]
+    fn test_render_json() {
+        assert_eq!(
+            Format::Json.render(&record()),
+            r#"{"day":9,"part":2,"answer":-2,"answer_type":"signed","elapsed_us":1500,"input":"inputs/day,9"}"#
+        );
+    }
+
+    #[test]
+    fn test_render_csv() {
+        assert_eq!(
+            Format::Csv.header(),
+            Some("day,part,answer,answer_type,elapsed_us,input")
+        );
+        assert_eq!(
+            Format::Csv.render(&record()),
+            "9,2,-2,signed,1500,\"inputs/day,9\""
+        );
+    }
+
+    #[test]
+    fn test_render_unsolved() {
+        let record = Record {
+            answer: Answer::Unsolved,
+            ..record()
+        };
+        assert_eq!(Format::Text.render(&record), "Day 9 Part 2: unsolved");
+        assert!(Format::Json
+            .render(&record)
+            .contains(r#""answer":null,"answer_type":"unsolved""#));
+        assert_eq!(
+            Format::Csv.render(&record),
+            "9,2,,unsolved,1500,\"inputs/day,9\""
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.tmpl");
+const MAIN_TEMPLATE: &str = include_str!("../templates/main.rs.tmpl");
+
+/// Fills in every `{{day}}` placeholder of a template.
+pub fn render(template: &str, day: u8) -> String {
+    template.replace("{{day}}", &day.to_string())
+}
+
+/// Appends `member` to the `members` list of a workspace manifest, keeping one entry per
+/// line as the root `Cargo.toml` does.
+pub fn add_member(manifest: &str, member: &str) -> Result<String, String> {
+    let entry = format!("\"{member}\"");
+    let start = manifest
+        .find("members = [")
+        .ok_or("Workspace manifest has no `members = [` list")?;
+    let end = start
+        + manifest[start..]
+            .find(']')
+            .ok_or("Workspace `members` list isn't closed")?;
+    if manifest[start..end].contains(&entry) {
+        return Err(format!("`{member}` is already a workspace member"));
+    }
+    let line_start = manifest[..end].rfind('\n').map_or(0, |idx| idx + 1);
+    Ok(format!(
+        "{}    {entry},\n{}",
+        &manifest[..line_start],
+        &manifest[line_start..]
+    ))
+}
+
+/// Creates the `day_N` crate under the workspace `root` and registers it as a member.
+/// Nothing is written when the crate directory or the member already exists.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    let name = format!("day_{day}");
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(format!("`{}` already exists", dir.display()));
+    }
+    let manifest_path = root.join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path)
+        .map_err(|err| format!("Failed to read `{}`: {err}", manifest_path.display()))?;
+    let manifest = add_member(&manifest, &name)?;
+
+    let files = [
+        (dir.join("Cargo.toml"), render(CARGO_TEMPLATE, day)),
+        (dir.join("src/lib.rs"), render(LIB_TEMPLATE, day)),
+        (dir.join("src/main.rs"), render(MAIN_TEMPLATE, day)),
+        (dir.join("example"), String::new()),
+        (dir.join("input"), String::new()),
+    ];
+    fs::create_dir_all(dir.join("src"))
+        .map_err(|err| format!("Failed to create `{}`: {err}", dir.display()))?;
+    let mut created = vec![];
+    for (path, contents) in files {
+        fs::write(&path, contents)
+            .map_err(|err| format!("Failed to write `{}`: {err}", path.display()))?;
+        created.push(path);
+    }
+    fs::write(&manifest_path, manifest)
+        .map_err(|err| format!("Failed to write `{}`: {err}", manifest_path.display()))?;
+    created.push(manifest_path);
+    Ok(created)
+}
+
+#[cfg(test)]
+mod scaffold_tests {
+    use std::env;
+
+    use crate::scaffold::*;
+
+    #[test]
+    fn test_render() {
+        let main = render(MAIN_TEMPLATE, 13);
+        assert!(main.contains("aoc_common::cli::run::<day_13::Day13>();"));
+        let lib = render(LIB_TEMPLATE, 13);
+        assert!(lib.contains("const DAY: u8 = 13;"));
+        assert!(lib.contains("mod day_13_tests"));
+        assert!(!lib.contains("{{day}}"));
+        assert!(!lib.contains("todo!"));
+    }
+
+    #[test]
+    fn test_add_member() {
+        let manifest =
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day_12\",\n]\nresolver = \"2\"\n";
+        assert_eq!(
+            add_member(manifest, "day_13"),
+            Ok(String::from(
+                "[workspace]\nmembers = [\n    \"aoc\",\n    \"day_12\",\n    \"day_13\",\n]\nresolver = \"2\"\n"
+            ))
+        );
+        assert!(add_member(manifest, "day_12").is_err());
+        assert!(add_member("[workspace]\n", "day_13").is_err());
+    }
+
+    #[test]
+    fn test_new_day_refuses_to_overwrite() {
+        let root = env::temp_dir().join(format!("aoc_scaffold_{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join("Cargo.toml"), "[workspace]\nmembers = [\n]\n").unwrap();
+
+        let created = new_day(&root, 13).unwrap();
+        assert_eq!(created.len(), 6);
+        assert_eq!(fs::read_to_string(root.join("day_13/input")).unwrap(), "");
+        assert!(fs::read_to_string(root.join("Cargo.toml"))
+            .unwrap()
+            .contains("\"day_13\""));
+
+        fs::write(root.join("day_13/input"), "puzzle").unwrap();
+        assert!(new_day(&root, 13).is_err());
+        assert_eq!(
+            fs::read_to_string(root.join("day_13/input")).unwrap(),
+            "puzzle"
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
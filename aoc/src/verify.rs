@@ -0,0 +1,163 @@
+use std::{fmt, path::PathBuf};
+
+use aoc_common::{
+    input::{workspace_path, InputSource},
+    Part,
+};
+
+use crate::{days, manifest::Manifest};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Missing,
+    Error,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Status::Pass => "PASS",
+            Status::Fail => "FAIL",
+            Status::Missing => "MISSING",
+            Status::Error => "ERROR",
+        };
+        f.pad(status)
+    }
+}
+
+/// One solution run compared against the manifest.
+#[derive(Debug)]
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub input: PathBuf,
+    pub expected: Option<String>,
+    pub actual: Result<String, String>,
+}
+
+impl Check {
+    pub fn status(&self) -> Status {
+        match (&self.expected, &self.actual) {
+            (_, Err(_)) => Status::Error,
+            (None, Ok(_)) => Status::Missing,
+            (Some(expected), Ok(actual)) if expected == actual => Status::Pass,
+            (Some(_), Ok(_)) => Status::Fail,
+        }
+    }
+}
+
+/// Every manifest entry for `days`, plus each day's own `day_N/input` so that parts without
+/// a recorded answer show up as missing.
+pub fn planned(manifest: &Manifest, days: &[u8]) -> Vec<(u8, Part, PathBuf)> {
+    let mut planned = vec![];
+    for day in days {
+        for part in [Part::One, Part::Two] {
+            for entry in manifest.entries.iter() {
+                if entry.day == *day && entry.part == part {
+                    planned.push((*day, part, entry.input.clone()));
+                }
+            }
+            let default = PathBuf::from(format!("day_{day}/input"));
+            if manifest.get(*day, part, &default).is_none() {
+                planned.push((*day, part, default));
+            }
+        }
+    }
+    planned
+}
+
+pub fn check(manifest: &Manifest, day: u8, part: Part, input: PathBuf) -> Check {
+    let source = InputSource::File(workspace_path(&input));
+    let actual = match days::find(day) {
+        Some(solution) => source
+            .read()
+            .map_err(|err| err.to_string())
+            .and_then(|text| solution.solve(&text, part).map_err(|err| err.to_string()))
+            .map(|answer| answer.to_string()),
+        None => Err(format!("Day {day} is not solved yet")),
+    };
+    let expected = manifest
+        .get(day, part, &input)
+        .map(|entry| entry.answer.clone());
+    Check {
+        day,
+        part,
+        input,
+        expected,
+        actual,
+    }
+}
+
+/// Runs every planned check, printing the table as it goes. Returns whether nothing failed.
+pub fn verify(manifest: &Manifest, days: &[u8]) -> bool {
+    println!(
+        "{:>3}  {:>4}  {:<20}  {:>15}  {:>15}  Status",
+        "Day", "Part", "Input", "Expected", "Actual"
+    );
+    let mut counts = [0; 4];
+    for (day, part, input) in planned(manifest, days) {
+        let check = check(manifest, day, part, input);
+        let status = check.status();
+        counts[status as usize] += 1;
+        let actual = match &check.actual {
+            Ok(actual) => actual.as_str(),
+            Err(_) => "-",
+        };
+        println!(
+            "{:>3}  {:>4}  {:<20}  {:>15}  {:>15}  {}",
+            check.day,
+            check.part,
+            check.input.display(),
+            check.expected.as_deref().unwrap_or("-"),
+            actual,
+            status
+        );
+        if let Err(err) = &check.actual {
+            println!("     {err}");
+        }
+    }
+    println!(
+        "{} passed, {} failed, {} missing, {} errors",
+        counts[Status::Pass as usize],
+        counts[Status::Fail as usize],
+        counts[Status::Missing as usize],
+        counts[Status::Error as usize]
+    );
+    counts[Status::Fail as usize] == 0 && counts[Status::Error as usize] == 0
+}
+
+#[cfg(test)]
+mod verify_tests {
+    use std::str::FromStr;
+
+    use crate::verify::*;
+
+    #[test]
+    fn test_planned_adds_missing_inputs() {
+        let manifest = Manifest::from_str("1 1 day_1/example 142\n1 1 day_1/input 1").unwrap();
+        let planned = planned(&manifest, &[1]);
+        assert_eq!(
+            planned,
+            vec![
+                (1, Part::One, PathBuf::from("day_1/example")),
+                (1, Part::One, PathBuf::from("day_1/input")),
+                (1, Part::Two, PathBuf::from("day_1/input")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_check_statuses() {
+        let manifest = Manifest::from_str("2 1 day_2/example 8\n2 2 day_2/example 1").unwrap();
+        let pass = check(&manifest, 2, Part::One, PathBuf::from("day_2/example"));
+        assert_eq!(pass.status(), Status::Pass);
+        let fail = check(&manifest, 2, Part::Two, PathBuf::from("day_2/example"));
+        assert_eq!(fail.status(), Status::Fail);
+        let no_file = check(&manifest, 2, Part::One, PathBuf::from("day_2/example-2"));
+        assert_eq!(no_file.status(), Status::Error);
+        let unknown = check(&manifest, 6, Part::One, PathBuf::from("day_6/example"));
+        assert_eq!(unknown.status(), Status::Missing);
+    }
+}
@@ -0,0 +1,89 @@
+use std::fmt;
+
+/// The result of a single puzzle part, regardless of the integer type a day computes with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    /// A part that isn't solved yet, such as the stubs of a newly scaffolded day.
+    Unsolved,
+}
+
+impl Answer {
+    /// Name of the variant, as reported in machine-readable output.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Unsigned(_) => "unsigned",
+            Answer::Signed(_) => "signed",
+            Answer::Unsolved => "unsolved",
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(value) => write!(f, "{value}"),
+            Answer::Signed(value) => write!(f, "{value}"),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Unsigned(value.into())
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Unsigned(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Unsigned(value as u64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Signed(value)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Answer::Signed(value as i64)
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Answer::Unsolved, Into::into)
+    }
+}
+
+#[cfg(test)]
+mod answer_tests {
+    use crate::*;
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Answer::from(142_u32), Answer::Unsigned(142));
+        assert_eq!(Answer::from(46_usize), Answer::Unsigned(46));
+        assert_eq!(Answer::from(-3_isize), Answer::Signed(-3));
+        assert_eq!(Answer::from(Some(7_u64)), Answer::Unsigned(7));
+        assert_eq!(Answer::from(None::<u64>), Answer::Unsolved);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::Unsigned(525152).to_string(), "525152");
+        assert_eq!(Answer::Signed(-3).to_string(), "-3");
+        assert_eq!(Answer::Signed(-3).kind(), "signed");
+        assert_eq!(Answer::Unsolved.to_string(), "unsolved");
+    }
+}
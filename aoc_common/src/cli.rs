@@ -0,0 +1,116 @@
+use std::{env, process};
+
+use crate::{input::InputSource, Solution};
+
+/// Command line arguments that are consumed flag by flag, so each binary only has to
+/// pick out the flags it knows and can reject whatever is left over.
+#[derive(Debug)]
+pub struct Args {
+    args: Vec<String>,
+}
+
+impl Args {
+    pub fn new(args: Vec<String>) -> Self {
+        Args { args }
+    }
+
+    pub fn from_env() -> Self {
+        Args::new(env::args().skip(1).collect())
+    }
+
+    /// Takes the leading positional argument, if there is one.
+    pub fn subcommand(&mut self) -> Option<String> {
+        match self.args.first() {
+            Some(arg) if !arg.starts_with('-') => Some(self.args.remove(0)),
+            _ => None,
+        }
+    }
+
+    /// Takes a switch such as `--all`, returning whether it was present.
+    pub fn flag(&mut self, name: &str) -> bool {
+        match self.args.iter().position(|arg| arg == name) {
+            Some(idx) => {
+                self.args.remove(idx);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Takes a flag with a value such as `--day 7`.
+    pub fn value(&mut self, name: &str) -> Result<Option<String>, String> {
+        let Some(idx) = self.args.iter().position(|arg| arg == name) else {
+            return Ok(None);
+        };
+        match self.args.get(idx + 1) {
+            Some(value) if value == "-" || !value.starts_with('-') => {
+                let value = self.args.remove(idx + 1);
+                self.args.remove(idx);
+                Ok(Some(value))
+            }
+            _ => Err(format!("Missing value for {name}")),
+        }
+    }
+
+    /// Fails on anything that no caller asked for.
+    pub fn finish(self) -> Result<(), String> {
+        match self.args.first() {
+            Some(arg) => Err(format!("Unknown argument `{arg}`")),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Entry point shared by the `day_N` binaries: reads the input given by `--input`
+/// (defaulting to `day_N/input`) and prints both parts.
+pub fn run<S: Solution>() {
+    exit_on_error(try_run::<S>(Args::from_env()));
+}
+
+/// [`run`] for binaries that consume their own flags first and hand the rest over.
+pub fn try_run<S: Solution>(mut args: Args) -> Result<(), String> {
+    let source = InputSource::from_arg(args.value("--input")?.as_deref(), S::DAY);
+    args.finish()?;
+
+    let input = source.read().map_err(|err| err.to_string())?;
+    let input = S::parse(&input).map_err(|err| err.to_string())?;
+    println!("Part 1: {}", S::part_one(&input));
+    println!("Part 2: {}", S::part_two(&input));
+    Ok(())
+}
+
+/// Prints the error and exits with status 1, so `main` can end with a `Result`.
+pub fn exit_on_error(result: Result<(), String>) {
+    if let Err(err) = result {
+        eprintln!("Error: {err}");
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod cli_tests {
+    use crate::cli::*;
+
+    fn args(line: &str) -> Args {
+        Args::new(line.split_whitespace().map(String::from).collect())
+    }
+
+    #[test]
+    fn test_consumes_known_flags() {
+        let mut args = args("run --day 7 --all --input -");
+        assert_eq!(args.subcommand().as_deref(), Some("run"));
+        assert_eq!(args.value("--day"), Ok(Some(String::from("7"))));
+        assert_eq!(args.value("--input"), Ok(Some(String::from("-"))));
+        assert_eq!(args.value("--part"), Ok(None));
+        assert!(args.flag("--all"));
+        assert!(args.finish().is_ok());
+    }
+
+    #[test]
+    fn test_rejects_leftovers() {
+        let mut args = args("--day --verbose");
+        assert!(args.subcommand().is_none());
+        assert!(args.value("--day").is_err());
+        assert!(args.finish().is_err());
+    }
+}
@@ -0,0 +1,116 @@
+use std::{fmt, str::FromStr};
+
+/// A malformed puzzle input, pointing at the offending text.
+///
+/// Parsers build the error against the slice they were given and callers that handed out
+/// that slice re-anchor it with [`ParseError::within`], so the reported line and column
+/// always refer to the original input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub reason: String,
+}
+
+impl ParseError {
+    pub fn new(day: u8, reason: impl Into<String>) -> Self {
+        ParseError {
+            day,
+            line: 1,
+            column: 1,
+            text: String::new(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Records `text` as the offending input, positioned by where it sits inside `source`.
+    pub fn at(mut self, source: &str, text: &str) -> Self {
+        (self.line, self.column) = position(source, text);
+        self.text = text.to_owned();
+        self
+    }
+
+    /// Moves an error reported relative to `inner` so it is relative to `outer`.
+    pub fn within(mut self, outer: &str, inner: &str) -> Self {
+        let (line, column) = position(outer, inner);
+        if self.line == 1 {
+            self.column += column - 1;
+        }
+        self.line += line - 1;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Day {}, line {}, column {}: {}",
+            self.day, self.line, self.column, self.reason
+        )?;
+        if !self.text.is_empty() {
+            write!(f, " `{}`", self.text)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `token`, a slice of `source`, reporting its position when it isn't a number.
+pub fn parse_number<T: FromStr>(day: u8, source: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::new(day, "invalid number").at(source, token))
+}
+
+/// One-based line and column of `inner` inside `outer`, or the start of `outer` when
+/// `inner` isn't one of its slices.
+fn position(outer: &str, inner: &str) -> (usize, usize) {
+    let offset = (inner.as_ptr() as usize)
+        .checked_sub(outer.as_ptr() as usize)
+        .filter(|offset| offset + inner.len() <= outer.len())
+        .unwrap_or(0);
+    let before = &outer[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+    (line, offset - line_start + 1)
+}
+
+#[cfg(test)]
+mod error_tests {
+    use crate::error::*;
+
+    #[test]
+    fn test_position_of_token() {
+        let input = "seeds: 1 2\n\nmap:\n1 x 3";
+        let token = &input[input.find('x').unwrap()..][..1];
+        let err = ParseError::new(5, "invalid number").at(input, token);
+        assert_eq!((err.line, err.column), (4, 3));
+        assert_eq!(
+            err.to_string(),
+            "Day 5, line 4, column 3: invalid number `x`"
+        );
+    }
+
+    #[test]
+    fn test_within_nested_slices() {
+        let input = "header\nab cd";
+        let line = input.lines().last().unwrap();
+        let token = &line[3..];
+        let err = ParseError::new(1, "bad")
+            .at(line, token)
+            .within(input, line);
+        assert_eq!((err.line, err.column), (2, 4));
+    }
+
+    #[test]
+    fn test_parse_number() {
+        let line = "41 48 x3";
+        assert_eq!(parse_number::<u32>(4, line, &line[3..5]), Ok(48));
+        let err = parse_number::<u32>(4, line, &line[6..]).unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (7, "x3"));
+    }
+}
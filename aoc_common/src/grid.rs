@@ -0,0 +1,255 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::ParseError;
+
+/// A rectangular map of cells stored row by row and addressed by `(x, y)`, with `x` the
+/// column and `y` the row counted from the top left.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+const NEIGHBORS_4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+const NEIGHBORS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+impl<T> Grid<T> {
+    /// Builds a grid from its cells in row-major order.
+    ///
+    /// Panics if `cells` doesn't hold exactly `width * height` values.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "grid must be rectangular");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Reads one cell per character and one row per line, converting each character with
+    /// `cell`. A rejected character or a line whose length differs from the first one is
+    /// reported at its position in `s`.
+    pub fn parse(
+        day: u8,
+        s: &str,
+        mut cell: impl FnMut(char) -> Result<T, String>,
+    ) -> Result<Self, ParseError> {
+        let width = s.lines().next().unwrap_or_default().chars().count();
+        let mut cells = vec![];
+        let mut height = 0;
+        for line in s.lines() {
+            for (idx, c) in line.char_indices() {
+                let value = cell(c).map_err(|reason| {
+                    ParseError::new(day, reason).at(s, &line[idx..idx + c.len_utf8()])
+                })?;
+                cells.push(value);
+            }
+            if line.chars().count() != width {
+                return Err(ParseError::new(day, format!("expected {width} columns")).at(s, line));
+            }
+            height += 1;
+        }
+        Ok(Grid::new(width, height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): (usize, usize)) -> Option<&T> {
+        self.contains((x, y))
+            .then(|| &self.cells[y * self.width + x])
+    }
+
+    pub fn get_mut(&mut self, (x, y): (usize, usize)) -> Option<&mut T> {
+        if self.contains((x, y)) {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// Position of `(x, y)` moved by `(dx, dy)`, or `None` when that falls off the grid.
+    pub fn offset(
+        &self,
+        (x, y): (usize, usize),
+        (dx, dy): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+        self.contains((x, y)).then_some((x, y))
+    }
+
+    /// The up to four orthogonally adjacent positions that lie on the grid.
+    pub fn neighbors4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBORS_4
+            .iter()
+            .filter_map(move |delta| self.offset(pos, *delta))
+    }
+
+    /// The up to eight orthogonally or diagonally adjacent positions that lie on the grid.
+    pub fn neighbors8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBORS_8
+            .iter()
+            .filter_map(move |delta| self.offset(pos, *delta))
+    }
+
+    /// Every position in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Position of the first cell, in row-major order, that matches `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} is outside the grid");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Swaps rows and columns, so `(x, y)` becomes `(y, x)`.
+    pub fn transpose(&self) -> Grid<T> {
+        let cells = self.columns().flat_map(|column| column.cloned()).collect();
+        Grid::new(self.height, self.width, cells)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("position {pos:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut Self::Output {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("position {pos:?} is outside the grid"))
+    }
+}
+
+/// Prints each row on its own line with the cells written back to back.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod grid_tests {
+    use crate::grid::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse(0, "abc\ndef", Ok).unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_index() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 0)], 'c');
+        assert_eq!(grid[(0, 1)], 'd');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.position(|c| *c == 'e'), Some((1, 1)));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Grid::parse(11, "..#\n.x.", |c| match c {
+            '.' | '#' => Ok(c),
+            _ => Err(String::from("expected `.` or `#`")),
+        })
+        .unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "x"));
+        let err = Grid::parse(11, "..#\n..", Ok).unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.reason.as_str()),
+            (2, 1, "expected 3 columns")
+        );
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = grid();
+        let corner: Vec<_> = grid.neighbors4((0, 0)).collect();
+        assert_eq!(corner, vec![(1, 0), (0, 1)]);
+        let edge: Vec<_> = grid.neighbors8((1, 1)).collect();
+        assert_eq!(edge, vec![(0, 0), (1, 0), (2, 0), (0, 1), (2, 1)]);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = grid();
+        let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, vec!["abc", "def"]);
+        let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+    }
+
+    #[test]
+    fn test_transpose_and_display() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.transpose().transpose(), grid);
+    }
+}
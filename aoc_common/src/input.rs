@@ -0,0 +1,138 @@
+use std::{
+    fmt, fs,
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+const WORKSPACE_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+/// Where a day's puzzle input is read from at runtime.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Maps an `--input` value to a source: `-` is stdin, anything else is a path and no
+    /// value at all falls back to the day's own `day_N/input`.
+    pub fn from_arg(arg: Option<&str>, day: u8) -> Self {
+        match arg {
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(PathBuf::from(path)),
+            None => InputSource::default_for(day),
+        }
+    }
+
+    /// `day_N/input`, see [`workspace_path`].
+    pub fn default_for(day: u8) -> Self {
+        InputSource::File(workspace_path(format!("day_{day}/input")))
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            InputSource::File(path) => fs::read_to_string(path).map_err(|err| self.error(err)),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|err| InputError::Io(self.clone(), err))?;
+                Ok(input)
+            }
+        }
+    }
+
+    /// Opens the source for reading line by line, for inputs too large to hold in memory.
+    pub fn open(&self) -> Result<Box<dyn BufRead>, InputError> {
+        match self {
+            InputSource::File(path) => match fs::File::open(path) {
+                Ok(file) => Ok(Box::new(BufReader::new(file))),
+                Err(err) => Err(self.error(err)),
+            },
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+
+    fn error(&self, err: io::Error) -> InputError {
+        match self {
+            InputSource::File(path) if err.kind() == io::ErrorKind::NotFound => {
+                InputError::Missing(path.clone())
+            }
+            _ => InputError::Io(self.clone(), err),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+/// Resolves a path relative to the working directory, or to the workspace root when the
+/// binary is started from somewhere else.
+pub fn workspace_path(relative: impl AsRef<Path>) -> PathBuf {
+    let relative = relative.as_ref();
+    if relative.is_absolute() || relative.exists() {
+        return relative.to_path_buf();
+    }
+    let from_root = Path::new(WORKSPACE_ROOT).join(relative);
+    if from_root.exists() {
+        return from_root;
+    }
+    relative.to_path_buf()
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing(PathBuf),
+    Io(InputSource, io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Missing(path) => write!(
+                f,
+                "Input file `{}` not found, pass --input <path> or --input - to read stdin",
+                path.display()
+            ),
+            InputError::Io(source, err) => write!(f, "Failed to read `{source}`: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+#[cfg(test)]
+mod input_tests {
+    use crate::input::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::from_arg(Some("-"), 1), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg(Some("other/input"), 1),
+            InputSource::File(PathBuf::from("other/input"))
+        );
+        assert_eq!(InputSource::from_arg(None, 7), InputSource::default_for(7));
+    }
+
+    #[test]
+    fn test_default_is_day_input() {
+        match InputSource::default_for(3) {
+            InputSource::File(path) => assert!(path.ends_with("day_3/input")),
+            InputSource::Stdin => panic!("Expected a file source"),
+        }
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let source = InputSource::File(PathBuf::from("day_0/does-not-exist"));
+        assert!(matches!(source.read(), Err(InputError::Missing(_))));
+        assert!(matches!(source.open(), Err(InputError::Missing(_))));
+    }
+}
@@ -0,0 +1,30 @@
+/// Quotes `s` as a JSON string literal.
+pub fn string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+#[cfg(test)]
+mod json_tests {
+    use crate::json::*;
+
+    #[test]
+    fn test_string() {
+        assert_eq!(string("day_7/input"), "\"day_7/input\"");
+        assert_eq!(string("a \"b\"\\\n"), "\"a \\\"b\\\"\\\\\\n\"");
+        assert_eq!(string("\u{1}"), "\"\\u0001\"");
+    }
+}
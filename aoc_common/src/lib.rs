@@ -0,0 +1,13 @@
+mod answer;
+pub mod cli;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod json;
+pub mod math;
+mod solution;
+
+pub use answer::Answer;
+pub use error::ParseError;
+pub use grid::Grid;
+pub use solution::{Part, Solution};
@@ -0,0 +1,125 @@
+/// Greatest common divisor, with `gcd(n, 0) == n` and `gcd(0, 0) == 0`.
+pub fn gcd(first: u64, second: u64) -> u64 {
+    gcd_u128(first.into(), second.into()) as u64
+}
+
+pub fn gcd_u128(mut first: u128, mut second: u128) -> u128 {
+    while second != 0 {
+        (first, second) = (second, first % second);
+    }
+    first
+}
+
+/// Least common multiple, or `None` when it doesn't fit in a `u64`. `lcm(n, 0)` is `0`.
+pub fn checked_lcm(first: u64, second: u64) -> Option<u64> {
+    checked_lcm_u128(first.into(), second.into())?
+        .try_into()
+        .ok()
+}
+
+pub fn checked_lcm_u128(first: u128, second: u128) -> Option<u128> {
+    if first == 0 || second == 0 {
+        return Some(0);
+    }
+    (first / gcd_u128(first, second)).checked_mul(second)
+}
+
+/// Returns `(g, x, y)` with `g = gcd(a, b) >= 0` and `a * x + b * y == g`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_s, mut s) = (1, 0);
+    let (mut old_t, mut t) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_s, s) = (s, old_s - quotient * s);
+        (old_t, t) = (t, old_t - quotient * t);
+    }
+    if old_r < 0 {
+        (-old_r, -old_s, -old_t)
+    } else {
+        (old_r, old_s, old_t)
+    }
+}
+
+/// [`extended_gcd`] for `i64`, or `None` when the result doesn't fit, which only happens
+/// for `i64::MIN` inputs.
+pub fn checked_extended_gcd(a: i64, b: i64) -> Option<(i64, i64, i64)> {
+    let (g, x, y) = extended_gcd(a.into(), b.into());
+    Some((g.try_into().ok()?, x.try_into().ok()?, y.try_into().ok()?))
+}
+
+/// Solves the system `x ≡ remainder (mod modulus)` for every `(remainder, modulus)` pair,
+/// whose moduli need not be coprime.
+///
+/// Returns the smallest non-negative `x` together with the combined modulus, i.e. the lcm
+/// of all moduli. `None` means the congruences contradict each other, a modulus is zero, or
+/// the combined modulus doesn't fit in a `u64`.
+pub fn crt(congruences: &[(u64, u64)]) -> Option<(u64, u64)> {
+    let (mut x, mut modulus): (i128, i128) = (0, 1);
+    for &(remainder, next) in congruences {
+        if next == 0 {
+            return None;
+        }
+        let (remainder, next) = (i128::from(remainder % next), i128::from(next));
+        let (g, p, _) = extended_gcd(modulus, next);
+        let difference = remainder - x;
+        if difference % g != 0 {
+            return None;
+        }
+        // p * modulus ≡ g (mod next), so stepping x by k * modulus fixes the difference.
+        let step = next / g;
+        let k = (difference / g % step)
+            .checked_mul(p % step)?
+            .rem_euclid(step);
+        let combined = modulus.checked_mul(step)?;
+        x = (x + modulus.checked_mul(k)?).rem_euclid(combined);
+        modulus = combined;
+        if modulus > i128::from(u64::MAX) {
+            return None;
+        }
+    }
+    Some((x as u64, modulus as u64))
+}
+
+#[cfg(test)]
+mod math_tests {
+    use crate::math::*;
+
+    #[test]
+    fn test_gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(checked_lcm(4, 6), Some(12));
+        assert_eq!(checked_lcm(0, 6), Some(0));
+        assert_eq!(checked_lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(
+            checked_lcm_u128(u64::MAX.into(), (u64::MAX - 1).into()),
+            Some(u128::from(u64::MAX) * u128::from(u64::MAX - 1))
+        );
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+        let (g, x, y) = extended_gcd(-12, 18);
+        assert_eq!(g, 6);
+        assert_eq!(-12 * x + 18 * y, 6);
+        assert_eq!(checked_extended_gcd(0, 0), Some((0, 1, 0)));
+        assert_eq!(checked_extended_gcd(i64::MIN, 0), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(3, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[(0, 11309), (0, 19199)]), Some((0, 43 * 73 * 263)));
+        assert_eq!(crt(&[(1, 0)]), None);
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(1, u64::MAX), (0, u64::MAX - 1)]), None);
+    }
+}
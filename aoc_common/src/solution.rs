@@ -0,0 +1,52 @@
+use std::{fmt, str::FromStr};
+
+use crate::{Answer, ParseError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            other => Err(format!("Invalid part `{other}`, expected 1 or 2")),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+/// A day's puzzle: the input is parsed once and both parts are answered from it.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_one(input: &Self::Input) -> Answer;
+
+    fn part_two(input: &Self::Input) -> Answer;
+
+    fn solve(input: &str, part: Part) -> Result<Answer, ParseError> {
+        let input = Self::parse(input)?;
+        let answer = match part {
+            Part::One => Self::part_one(&input),
+            Part::Two => Self::part_two(&input),
+        };
+        Ok(answer)
+    }
+}
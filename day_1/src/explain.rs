@@ -0,0 +1,80 @@
+use std::fmt;
+
+use crate::{stream::accumulate, CalibrationError, Token, Vocabulary};
+
+/// How one line of the calibration document contributed to the total.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Trace<'a> {
+    pub line_number: usize,
+    pub line: &'a str,
+    pub first: Option<Token<'a>>,
+    pub last: Option<Token<'a>>,
+    pub value: Option<u64>,
+    pub total: u64,
+}
+
+impl Trace<'_> {
+    /// Lines without a single token add nothing and usually point at a vocabulary problem.
+    pub fn has_no_digits(&self) -> bool {
+        self.value.is_none()
+    }
+}
+
+impl fmt::Display for Trace<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {} -> ", self.line_number, self.line)?;
+        match (self.first, self.last, self.value) {
+            (Some(first), Some(last), Some(value)) => write!(
+                f,
+                "first `{}` at {}, last `{}` at {}, value {}",
+                first.text, first.offset, last.text, last.offset, value
+            )?,
+            _ => write!(f, "NO DIGITS")?,
+        }
+        write!(f, ", total {}", self.total)
+    }
+}
+
+/// Traces every line of `file` in order, keeping a running total. Stops after the line
+/// where the total overflows a `u64`.
+pub fn explain<'a>(
+    file: &'a str,
+    vocabulary: &'a Vocabulary,
+) -> impl Iterator<Item = Result<Trace<'a>, CalibrationError>> + 'a {
+    let mut total = Some(0);
+    file.lines().enumerate().map_while(move |(idx, line)| {
+        let value = vocabulary.calibration(line);
+        let trace = accumulate(total?, value, idx + 1).map(|total| Trace {
+            line_number: idx + 1,
+            line,
+            first: vocabulary.first(line),
+            last: vocabulary.last(line),
+            value,
+            total,
+        });
+        total = trace.as_ref().ok().map(|trace| trace.total);
+        Some(trace)
+    })
+}
+
+#[cfg(test)]
+mod explain_tests {
+    use crate::explain::*;
+
+    #[test]
+    fn test_explain() {
+        let vocabulary = Vocabulary::spelled();
+        let traces: Vec<Trace> = explain("two1nine\nabc\nxtwone3four", &vocabulary)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(traces.len(), 3);
+        assert_eq!(
+            traces[0].to_string(),
+            "line 1: two1nine -> first `two` at 0, last `nine` at 4, value 29, total 29"
+        );
+        assert!(traces[1].has_no_digits());
+        assert_eq!(traces[1].to_string(), "line 2: abc -> NO DIGITS, total 29");
+        assert_eq!(traces[2].value, Some(24));
+        assert_eq!(traces[2].total, 53);
+    }
+}
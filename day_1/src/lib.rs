@@ -0,0 +1,66 @@
+mod explain;
+mod stream;
+mod vocabulary;
+
+use aoc_common::{Answer, ParseError, Solution};
+
+pub use explain::{explain, Trace};
+pub use stream::{calibrate, calibrate_many, CalibrationError};
+pub use vocabulary::{Token, Vocabulary, BUILTINS};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(str::to_owned).collect())
+    }
+
+    fn part_one(lines: &Self::Input) -> Answer {
+        total(&Vocabulary::digits(), lines).into()
+    }
+
+    fn part_two(lines: &Self::Input) -> Answer {
+        total(&Vocabulary::spelled(), lines).into()
+    }
+}
+
+fn total(vocabulary: &Vocabulary, lines: &[String]) -> u64 {
+    vocabulary
+        .total(lines.iter().map(String::as_str))
+        .unwrap_or_else(|err| panic!("{err}"))
+}
+
+pub fn part_one(file: &str) -> Result<u64, CalibrationError> {
+    calibrate(file.as_bytes(), &Vocabulary::digits())
+}
+
+pub fn part_two(file: &str) -> Result<u64, CalibrationError> {
+    calibrate(file.as_bytes(), &Vocabulary::spelled())
+}
+
+#[cfg(test)]
+mod day_1_tests {
+    use crate::*;
+
+    #[test]
+    fn test_part_1_example() {
+        assert_eq!(part_one(include_str!("../example")).unwrap(), 142);
+    }
+
+    #[test]
+    fn test_part_2_example() {
+        assert_eq!(part_two(include_str!("../example-2")).unwrap(), 281);
+    }
+
+    #[test]
+    fn test_parse() {
+        let lines = Day1::parse(include_str!("../example-2")).unwrap();
+        assert_eq!(lines.len(), 7);
+        assert_eq!(Day1::part_one(&lines), Answer::Unsigned(209));
+        assert_eq!(Day1::part_two(&lines), Answer::Unsigned(281));
+    }
+}
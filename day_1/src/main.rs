@@ -1,75 +1,54 @@
-fn main() {
-    let file = include_str!("../input").to_owned();
-    println!("Part 1: {}", part_one(String::from(&file)));
-    println!("Part 2: {}", part_two(file));
-}
-
-pub fn part_one(file: String) -> u32 {
-    let res: u32 = file
-        .lines()
-        .map(|line| {
-            let nums: Vec<char> = line.chars().filter(|&char| char.is_ascii_digit()).collect();
-            let first = nums.first().unwrap().to_owned();
-            let second = nums.last().unwrap().to_owned();
-            let number = format!("{}{}", first, second).parse::<u32>().unwrap_or(0);
-            number
-        })
-        .sum();
-    res
-}
+use aoc_common::{
+    cli::{self, Args},
+    input::InputSource,
+    Solution,
+};
+use day_1::{Day1, Vocabulary};
 
-pub fn part_two(file: String) -> u32 {
-    let spelled = vec![
-        "oneight",
-        "twone",
-        "threeight",
-        "fiveight",
-        "sevenine",
-        "eightwo",
-        "eighthree",
-        "nineight",
-        "one",
-        "two",
-        "three",
-        "four",
-        "five",
-        "six",
-        "seven",
-        "eight",
-        "nine",
-    ];
-    let values = vec![
-        "18", "21", "38", "58", "79", "82", "83", "98", "1", "2", "3", "4", "5", "6", "7", "8", "9",
-    ];
-
-    let res: u32 = file
-        .lines()
-        .map(|line| {
-            let mut line = line.to_owned();
-            for (i, num) in spelled.iter().enumerate() {
-                line = line.replace(num, values[i]);
-            }
-            let nums: Vec<char> = line.chars().filter(|&char| char.is_ascii_digit()).collect();
-            let first = nums.first().unwrap().to_owned();
-            let second = nums.last().unwrap().to_owned();
-            let number = format!("{}{}", first, second).parse::<u32>().unwrap_or(0);
-            number
-        })
-        .sum();
-    res
+fn main() {
+    cli::exit_on_error(run(Args::from_env()));
 }
 
-#[cfg(test)]
-mod day_1_tests {
-    use crate::*;
-
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_one(include_str!("../example").to_owned()), 142);
+/// Streams the input line by line to solve both parts unless `--vocabulary` or `--explain`
+/// is given. `--vocabulary` takes a comma separated list of built-in vocabularies and
+/// vocabulary files and streams the input the same way to sum the calibration values with
+/// it; `--explain` instead reads the whole input to trace every line, using part two's
+/// `spelled` vocabulary when none is given.
+fn run(mut args: Args) -> Result<(), String> {
+    let spec = args.value("--vocabulary")?;
+    let explain = args.flag("--explain");
+    let source = InputSource::from_arg(args.value("--input")?.as_deref(), Day1::DAY);
+    args.finish()?;
+    if spec.is_none() && !explain {
+        let reader = source.open().map_err(|err| err.to_string())?;
+        let vocabularies = [&Vocabulary::digits(), &Vocabulary::spelled()];
+        let totals = day_1::calibrate_many(reader, &vocabularies)
+            .map_err(|err| format!("Failed to read `{source}`: {err}"))?;
+        println!("Part 1: {}", totals[0]);
+        println!("Part 2: {}", totals[1]);
+        return Ok(());
     }
+    let vocabulary = Vocabulary::load(spec.as_deref().unwrap_or("spelled"))?;
 
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(part_two(include_str!("../example-2").to_owned()), 281);
-    }
+    let total = if explain {
+        let input = source.read().map_err(|err| err.to_string())?;
+        let mut total = 0;
+        let mut without_digits = 0;
+        for trace in day_1::explain(&input, &vocabulary) {
+            let trace = trace.map_err(|err| format!("Failed to explain `{source}`: {err}"))?;
+            total = trace.total;
+            without_digits += usize::from(trace.has_no_digits());
+            println!("{trace}");
+        }
+        if without_digits > 0 {
+            println!("Lines without digits: {without_digits}");
+        }
+        total
+    } else {
+        let reader = source.open().map_err(|err| err.to_string())?;
+        day_1::calibrate(reader, &vocabulary)
+            .map_err(|err| format!("Failed to read `{source}`: {err}"))?
+    };
+    println!("Calibration: {total}");
+    Ok(())
 }
@@ -0,0 +1,105 @@
+use std::{fmt, io::BufRead};
+
+use crate::Vocabulary;
+
+#[derive(Debug)]
+pub enum CalibrationError {
+    Io(std::io::Error),
+    /// The running total no longer fits in a `u64` after adding this line.
+    Overflow {
+        line: usize,
+    },
+}
+
+impl fmt::Display for CalibrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalibrationError::Io(err) => write!(f, "{err}"),
+            CalibrationError::Overflow { line } => {
+                write!(f, "calibration total overflows a u64 at line {line}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CalibrationError {}
+
+/// Sums the calibration values of `reader` one line at a time, reusing a single line
+/// buffer so memory use doesn't grow with the input.
+pub fn calibrate(reader: impl BufRead, vocabulary: &Vocabulary) -> Result<u64, CalibrationError> {
+    let totals = calibrate_many(reader, &[vocabulary])?;
+    Ok(totals[0])
+}
+
+/// [`calibrate`] with several vocabularies in a single pass, one total per vocabulary.
+pub fn calibrate_many(
+    mut reader: impl BufRead,
+    vocabularies: &[&Vocabulary],
+) -> Result<Vec<u64>, CalibrationError> {
+    let mut line = String::new();
+    let mut totals = vec![0; vocabularies.len()];
+    let mut line_number = 0;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line).map_err(CalibrationError::Io)? == 0 {
+            return Ok(totals);
+        }
+        line_number += 1;
+        let trimmed = line.trim_end_matches(['\n', '\r']);
+        for (total, vocabulary) in totals.iter_mut().zip(vocabularies) {
+            *total = accumulate(*total, vocabulary.calibration(trimmed), line_number)?;
+        }
+    }
+}
+
+/// Adds the calibration value of line number `line` to the running total, treating a line
+/// without a token as 0.
+pub(crate) fn accumulate(
+    total: u64,
+    value: Option<u64>,
+    line: usize,
+) -> Result<u64, CalibrationError> {
+    total
+        .checked_add(value.unwrap_or(0))
+        .ok_or(CalibrationError::Overflow { line })
+}
+
+#[cfg(test)]
+mod stream_tests {
+    use crate::stream::*;
+
+    #[test]
+    fn test_calibrate_reader() {
+        let example = include_str!("../example-2");
+        assert_eq!(
+            calibrate(example.as_bytes(), &Vocabulary::spelled()).unwrap(),
+            281
+        );
+        let crlf = "1abc2\r\nno digits\r\ntreb7uchet";
+        assert_eq!(
+            calibrate(crlf.as_bytes(), &Vocabulary::digits()).unwrap(),
+            89
+        );
+    }
+
+    #[test]
+    fn test_calibrate_many() {
+        let example = include_str!("../example-2");
+        let vocabularies = [&Vocabulary::digits(), &Vocabulary::spelled()];
+        assert_eq!(
+            calibrate_many(example.as_bytes(), &vocabularies).unwrap(),
+            [209, 281]
+        );
+        assert!(calibrate_many(example.as_bytes(), &[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_accumulate_overflow() {
+        assert_eq!(accumulate(10, Some(42), 1).unwrap(), 52);
+        assert_eq!(accumulate(10, None, 1).unwrap(), 10);
+        assert!(matches!(
+            accumulate(u64::MAX - 10, Some(42), 7),
+            Err(CalibrationError::Overflow { line: 7 })
+        ));
+    }
+}
@@ -0,0 +1,303 @@
+use std::str::FromStr;
+
+use aoc_common::{
+    error::parse_number,
+    input::{InputError, InputSource},
+    ParseError, Solution,
+};
+
+use crate::{stream::accumulate, CalibrationError, Day1};
+
+/// A vocabulary entry found in a line, starting `offset` bytes into it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token<'a> {
+    pub offset: usize,
+    pub text: &'a str,
+    pub value: u32,
+}
+
+/// The words a calibration line may spell its digits with, each mapped to the digit it
+/// stands for. Matches may overlap, so `twone` holds both a `two` and a `one`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Vocabulary {
+    entries: Vec<(String, u32)>,
+}
+
+const DIGITS: [(&str, u32); 10] = [
+    ("0", 0),
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+];
+
+const ENGLISH: [(&str, u32); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+const ORDINALS: [(&str, u32); 9] = [
+    ("first", 1),
+    ("second", 2),
+    ("third", 3),
+    ("fourth", 4),
+    ("fifth", 5),
+    ("sixth", 6),
+    ("seventh", 7),
+    ("eighth", 8),
+    ("ninth", 9),
+];
+
+const GERMAN: [(&str, u32); 10] = [
+    ("null", 0),
+    ("eins", 1),
+    ("zwei", 2),
+    ("drei", 3),
+    ("vier", 4),
+    ("fünf", 5),
+    ("sechs", 6),
+    ("sieben", 7),
+    ("acht", 8),
+    ("neun", 9),
+];
+
+const SPANISH: [(&str, u32); 10] = [
+    ("cero", 0),
+    ("uno", 1),
+    ("dos", 2),
+    ("tres", 3),
+    ("cuatro", 4),
+    ("cinco", 5),
+    ("seis", 6),
+    ("siete", 7),
+    ("ocho", 8),
+    ("nueve", 9),
+];
+
+/// Names accepted by [`Vocabulary::builtin`].
+pub const BUILTINS: [&str; 6] = [
+    "digits", "english", "spelled", "ordinals", "german", "spanish",
+];
+
+impl Vocabulary {
+    pub fn new<S: Into<String>>(entries: impl IntoIterator<Item = (S, u32)>) -> Self {
+        let entries = entries
+            .into_iter()
+            .map(|(word, value)| (word.into(), value))
+            .filter(|(word, _)| !word.is_empty())
+            .collect();
+        Vocabulary { entries }
+    }
+
+    /// Numeric digits only, as read by part one.
+    pub fn digits() -> Self {
+        Vocabulary::new(DIGITS)
+    }
+
+    /// Numeric digits plus the English words `one` to `nine`, as read by part two.
+    pub fn spelled() -> Self {
+        Vocabulary::digits().with(Vocabulary::new(ENGLISH))
+    }
+
+    /// One of the [`BUILTINS`]. Only `digits` and `spelled` include numeric digits, the
+    /// others are words alone and are meant to be combined with [`Vocabulary::with`].
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name {
+            "digits" => Some(Vocabulary::digits()),
+            "english" => Some(Vocabulary::new(ENGLISH)),
+            "spelled" => Some(Vocabulary::spelled()),
+            "ordinals" => Some(Vocabulary::new(ORDINALS)),
+            "german" => Some(Vocabulary::new(GERMAN)),
+            "spanish" => Some(Vocabulary::new(SPANISH)),
+            _ => None,
+        }
+    }
+
+    /// Resolves a comma separated list of built-in names and vocabulary file paths, such
+    /// as `digits,german,words.txt`, into one vocabulary.
+    pub fn load(spec: &str) -> Result<Self, String> {
+        spec.split(',')
+            .try_fold(Vocabulary::default(), |vocabulary, name| {
+                let other = match Vocabulary::builtin(name) {
+                    Some(builtin) => builtin,
+                    None => {
+                        let source = InputSource::File(name.into());
+                        let text = source.read().map_err(|err| match err {
+                            InputError::Missing(_) => format!(
+                                "Unknown vocabulary `{name}`, expected a file or one of {}",
+                                BUILTINS.join(", ")
+                            ),
+                            err => err.to_string(),
+                        })?;
+                        Vocabulary::from_str(&text)
+                            .map_err(|err| format!("Failed to parse `{source}`: {err}"))?
+                    }
+                };
+                Ok(vocabulary.with(other))
+            })
+    }
+
+    /// Adds every entry of `other`.
+    pub fn with(mut self, other: Vocabulary) -> Self {
+        self.entries.extend(other.entries);
+        self
+    }
+
+    /// The longest entry that starts exactly at `offset`.
+    fn token_at<'a>(&self, line: &'a str, offset: usize) -> Option<Token<'a>> {
+        let rest = &line[offset..];
+        self.entries
+            .iter()
+            .filter(|(word, _)| rest.starts_with(word.as_str()))
+            .max_by_key(|(word, _)| word.len())
+            .map(|(word, value)| Token {
+                offset,
+                text: &rest[..word.len()],
+                value: *value,
+            })
+    }
+
+    pub fn first<'a>(&self, line: &'a str) -> Option<Token<'a>> {
+        line.char_indices()
+            .find_map(|(offset, _)| self.token_at(line, offset))
+    }
+
+    pub fn last<'a>(&self, line: &'a str) -> Option<Token<'a>> {
+        line.char_indices()
+            .rev()
+            .find_map(|(offset, _)| self.token_at(line, offset))
+    }
+
+    /// The calibration value of a line: its first and last token read as a two-digit
+    /// number, or `None` if the line has no token at all. Entries built with
+    /// [`Vocabulary::new`] may stand for values above 9, so the value is widened to `u64`.
+    pub fn calibration(&self, line: &str) -> Option<u64> {
+        let first = self.first(line)?;
+        let last = self.last(line)?;
+        u64::from(first.value)
+            .checked_mul(10)?
+            .checked_add(last.value.into())
+    }
+
+    /// Sum of the calibration values of every line, skipping lines without a token.
+    pub fn total<'a>(
+        &self,
+        lines: impl IntoIterator<Item = &'a str>,
+    ) -> Result<u64, CalibrationError> {
+        lines
+            .into_iter()
+            .enumerate()
+            .try_fold(0, |total, (idx, line)| {
+                accumulate(total, self.calibration(line), idx + 1)
+            })
+    }
+}
+
+/// A vocabulary file: one `word digit` entry per line, with blank lines and `#` comments
+/// ignored.
+impl FromStr for Vocabulary {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut entries = vec![];
+        for line in s.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = trimmed.split_whitespace().collect();
+            let [word, value] = fields[..] else {
+                return Err(ParseError::new(Day1::DAY, "expected `<word> <digit>`").at(s, trimmed));
+            };
+            let value: u32 = parse_number(Day1::DAY, s, value)?;
+            if value > 9 {
+                return Err(
+                    ParseError::new(Day1::DAY, "expected a digit from 0 to 9").at(s, fields[1])
+                );
+            }
+            entries.push((word.to_owned(), value));
+        }
+        Ok(Vocabulary::new(entries))
+    }
+}
+
+#[cfg(test)]
+mod vocabulary_tests {
+    use crate::vocabulary::*;
+
+    #[test]
+    fn test_overlapping_words() {
+        let vocabulary = Vocabulary::spelled();
+        assert_eq!(vocabulary.calibration("twone"), Some(21));
+        assert_eq!(vocabulary.calibration("sevenineightwo"), Some(72));
+        assert_eq!(vocabulary.calibration("xoneightx"), Some(18));
+        assert_eq!(vocabulary.calibration("eightwothreeighthree"), Some(83));
+        assert_eq!(vocabulary.calibration("abc"), None);
+    }
+
+    #[test]
+    fn test_token_offsets() {
+        let vocabulary = Vocabulary::spelled();
+        let line = "zoneight234";
+        assert_eq!(
+            vocabulary.first(line),
+            Some(Token {
+                offset: 1,
+                text: "one",
+                value: 1
+            })
+        );
+        assert_eq!(
+            vocabulary.last(line),
+            Some(Token {
+                offset: 10,
+                text: "4",
+                value: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_total() {
+        let vocabulary = Vocabulary::digits();
+        let lines = "1abc2\nno digits\ntreb7uchet".lines();
+        assert_eq!(vocabulary.total(lines).unwrap(), 89);
+        let huge = Vocabulary::new([("x", u32::MAX)]);
+        assert_eq!(huge.calibration("x"), Some(u64::from(u32::MAX) * 11));
+    }
+
+    #[test]
+    fn test_builtins() {
+        for name in BUILTINS {
+            assert!(Vocabulary::builtin(name).is_some(), "{name}");
+        }
+        let vocabulary = Vocabulary::load("digits,german,ordinals").unwrap();
+        assert_eq!(vocabulary.calibration("xfünfx3zweifirst"), Some(51));
+        assert_eq!(vocabulary.calibration("null7"), Some(7));
+        assert!(Vocabulary::load("digits,klingon").is_err());
+    }
+
+    #[test]
+    fn test_vocabulary_file() {
+        let vocabulary = Vocabulary::from_str("# Italian\nuno 1\n\ndue 2\nzero 0\n").unwrap();
+        assert_eq!(vocabulary.calibration("duezerouno"), Some(21));
+        assert_eq!(vocabulary.calibration("zerodue"), Some(2));
+
+        let err = Vocabulary::from_str("uno 1\ndue").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "due"));
+        let err = Vocabulary::from_str("uno 1\ndieci 10").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 7, "10"));
+    }
+}
@@ -0,0 +1,209 @@
+use std::{collections::HashSet, str::FromStr};
+
+use aoc_common::{Answer, Grid, ParseError, Solution};
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Pipe {
+    shape: char,
+    pos: (usize, usize),
+}
+
+impl From<(char, (usize, usize))> for Pipe {
+    fn from(value: (char, (usize, usize))) -> Self {
+        Pipe {
+            shape: value.0,
+            pos: (value.1 .0, value.1 .1),
+        }
+    }
+}
+
+impl Pipe {
+    /// The two tiles this pipe opens towards, if it is a pipe at all.
+    fn ends(&self) -> impl Iterator<Item = (isize, isize)> {
+        let (x, y) = (self.pos.0 as isize, self.pos.1 as isize);
+        let openings: &[(isize, isize)] = match self.shape {
+            '|' => &[(0, -1), (0, 1)],
+            '-' => &[(-1, 0), (1, 0)],
+            'L' => &[(0, -1), (1, 0)],
+            'J' => &[(0, -1), (-1, 0)],
+            '7' => &[(0, 1), (-1, 0)],
+            'F' => &[(0, 1), (1, 0)],
+            _ => &[],
+        };
+        openings.iter().map(move |(dx, dy)| (x + dx, y + dy))
+    }
+
+    fn connects_to(&self, at: (isize, isize)) -> bool {
+        self.ends().any(|end| end == at)
+    }
+
+    /// The tile this pipe leads to when entered from `prev`, or `None` if it has no
+    /// opening towards `prev`.
+    fn next(&self, prev: &Pipe) -> Option<(isize, isize)> {
+        let from = (prev.pos.0 as isize, prev.pos.1 as isize);
+        if !self.connects_to(from) {
+            return None;
+        }
+        self.ends().find(|end| *end != from)
+    }
+}
+
+#[derive(Debug)]
+pub struct Maze {
+    tiles: Grid<char>,
+    start: (usize, usize),
+}
+
+impl FromStr for Maze {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tiles = Grid::parse(Day10::DAY, s, |c| match c {
+            '.' | '|' | '-' | 'L' | 'J' | '7' | 'F' | 'S' => Ok(c),
+            _ => Err(String::from("unknown tile")),
+        })?;
+        let start = tiles
+            .position(|tile| *tile == 'S')
+            .ok_or_else(|| ParseError::new(Day10::DAY, "missing start tile `S`"))?;
+        let start_tile = s.find('S').map_or("", |idx| &s[idx..idx + 1]);
+        let maze = Maze { tiles, start };
+        if maze.start_pipes().count() != 2 {
+            return Err(ParseError::new(
+                Day10::DAY,
+                "start tile must connect to exactly two pipes",
+            )
+            .at(s, start_tile));
+        }
+        if maze.walk().is_none() {
+            return Err(
+                ParseError::new(Day10::DAY, "start tile is not on a closed loop").at(s, start_tile),
+            );
+        }
+        Ok(maze)
+    }
+}
+
+impl Maze {
+    fn pipe_at(&self, pos: (isize, isize)) -> Option<Pipe> {
+        let pos = (usize::try_from(pos.0).ok()?, usize::try_from(pos.1).ok()?);
+        self.tiles.get(pos).map(|shape| Pipe::from((*shape, pos)))
+    }
+
+    /// The neighbours of `S` whose openings point back at it.
+    fn start_pipes(&self) -> impl Iterator<Item = Pipe> + '_ {
+        let start = (self.start.0 as isize, self.start.1 as isize);
+        self.tiles
+            .neighbors4(self.start)
+            .map(|pos| Pipe::from((self.tiles[pos], pos)))
+            .filter(move |pipe| pipe.connects_to(start))
+    }
+
+    /// The loop from `S` back to `S`, or `None` if the pipes lead onto a tile that isn't
+    /// connected, off the map, or back onto a tile already walked.
+    fn walk(&self) -> Option<Vec<Pipe>> {
+        let mut current_pipe = self.start_pipes().next()?;
+        let mut prev = Pipe::from(('S', self.start));
+        let mut path: Vec<Pipe> = vec![current_pipe];
+        let mut visited = HashSet::from([self.start]);
+
+        while current_pipe.shape != 'S' {
+            if !visited.insert(current_pipe.pos) {
+                return None;
+            }
+            let next = self.pipe_at(current_pipe.next(&prev)?)?;
+            prev = current_pipe;
+            current_pipe = next;
+            path.push(current_pipe);
+        }
+        Some(path)
+    }
+
+    /// The loop through `S`, which [`Maze::from_str`] has already checked is closed.
+    fn main_loop(&self) -> Vec<Pipe> {
+        self.walk().expect("loop is checked when parsing")
+    }
+}
+
+fn polygon_area(vertices: &[Pipe]) -> usize {
+    let number_of_vertices = vertices.len();
+    let mut sum1: isize = 0;
+    let mut sum2: isize = 0;
+
+    for i in 0..number_of_vertices {
+        let current = vertices[i].pos;
+        let next = vertices[(i + 1) % number_of_vertices].pos;
+        sum1 += current.0 as isize * next.1 as isize;
+        sum2 += current.1 as isize * next.0 as isize;
+    }
+
+    (sum1 - sum2).unsigned_abs() / 2
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Maze;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Maze::from_str(input)
+    }
+
+    fn part_one(maze: &Self::Input) -> Answer {
+        (maze.main_loop().len() / 2).into()
+    }
+
+    fn part_two(maze: &Self::Input) -> Answer {
+        let path = maze.main_loop();
+        let area = polygon_area(&path);
+        (area - (path.len() / 2) + 1).into()
+    }
+}
+
+pub fn part_one(file: &str) -> usize {
+    let maze = Maze::from_str(file).unwrap_or_else(|err| panic!("{err}"));
+    let path = maze.main_loop();
+    path.len() / 2
+}
+
+pub fn part_two(file: &str) -> usize {
+    let maze = Maze::from_str(file).unwrap_or_else(|err| panic!("{err}"));
+    let path = maze.main_loop();
+    let area = polygon_area(&path);
+    area - (path.len() / 2) + 1
+}
+
+#[cfg(test)]
+mod day_10_tests {
+    use crate::*;
+
+    #[test]
+    fn test_part_1_example() {
+        assert_eq!(part_one(include_str!("../example")), 8);
+    }
+
+    #[test]
+    fn test_part_2_example() {
+        assert_eq!(part_two(include_str!("../example")), 1);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Maze::from_str("..F7.\n.FJ|.\nSJ.X7").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 4, "X"));
+        assert!(Maze::from_str("..F7.\n.FJ|.").is_err());
+    }
+
+    #[test]
+    fn test_broken_loops() {
+        let err = Maze::from_str("S.\n..").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 1, "S"));
+        let err = Maze::from_str(".S-.\n....").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 2, "S"));
+        let err = Maze::from_str("S-\n|.").unwrap_err();
+        assert_eq!(err.reason, "start tile is not on a closed loop");
+        assert!(Maze::from_str("S-7\n|.|\nL-.").is_err());
+        assert!(Maze::from_str("S-7\n|.|\nL-J").is_ok());
+    }
+}
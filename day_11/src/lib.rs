@@ -0,0 +1,149 @@
+use std::str::FromStr;
+
+use aoc_common::{Answer, Grid, ParseError, Solution};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Galaxy {
+    x: usize,
+    y: usize,
+}
+
+#[derive(Debug)]
+pub struct Map {
+    layout: Grid<char>,
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let layout = Grid::parse(Day11::DAY, s, |c| match c {
+            '.' | '#' => Ok(c),
+            _ => Err(String::from("expected `.` or `#`")),
+        })?;
+        let res = Map { layout };
+        Ok(res)
+    }
+}
+
+impl Map {
+    fn expand(&self, degree: usize) -> Vec<(Galaxy, Galaxy)> {
+        let empty_rows: Vec<usize> = self
+            .layout
+            .rows()
+            .enumerate()
+            .filter_map(|(row, cells)| cells.iter().all(|c| *c == '.').then_some(row))
+            .collect();
+        let empty_cols: Vec<usize> = self
+            .layout
+            .columns()
+            .enumerate()
+            .filter_map(|(col, mut column)| column.all(|c| *c == '.').then_some(col))
+            .collect();
+
+        let mut galaxies = self.get_galxies();
+        for galaxy in galaxies.iter_mut() {
+            let mut x = 0;
+            let mut y = 0;
+            for row in empty_rows.iter() {
+                if galaxy.y > *row {
+                    y += degree;
+                }
+            }
+            for col in empty_cols.iter() {
+                if galaxy.x > *col {
+                    x += degree;
+                }
+            }
+            galaxy.x += x;
+            galaxy.y += y;
+        }
+        self.get_pairs_from(galaxies)
+    }
+    fn get_pairs_from(&self, galaxies: Vec<Galaxy>) -> Vec<(Galaxy, Galaxy)> {
+        let mut pairs: Vec<(Galaxy, Galaxy)> = vec![];
+        for (idx, galaxy) in galaxies.iter().enumerate() {
+            for other in galaxies.iter().skip(idx + 1) {
+                pairs.push((*galaxy, *other));
+            }
+        }
+        pairs
+    }
+
+    fn get_galxies(&self) -> Vec<Galaxy> {
+        self.layout
+            .iter()
+            .filter(|(_, c)| **c == '#')
+            .map(|((x, y), _)| Galaxy { x, y })
+            .collect()
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Map::from_str(input)
+    }
+
+    fn part_one(map: &Self::Input) -> Answer {
+        sum_of_distances(&map.expand(1)).into()
+    }
+
+    fn part_two(map: &Self::Input) -> Answer {
+        sum_of_distances(&map.expand(999999)).into()
+    }
+}
+
+fn sum_of_distances(pairs: &[(Galaxy, Galaxy)]) -> usize {
+    pairs
+        .iter()
+        .map(|(g1, g2)| g2.x.abs_diff(g1.x) + g2.y.abs_diff(g1.y))
+        .sum()
+}
+
+pub fn part_one(file: &str) -> usize {
+    let map = Map::from_str(file).unwrap_or_else(|err| panic!("{err}"));
+    sum_of_distances(&map.expand(1))
+}
+
+pub fn part_two(file: &str) -> usize {
+    let map = Map::from_str(file).unwrap_or_else(|err| panic!("{err}"));
+    sum_of_distances(&map.expand(999999))
+}
+
+#[cfg(test)]
+mod day_11_tests {
+    use crate::*;
+
+    #[test]
+    fn test_part_1_example() {
+        assert_eq!(part_one(include_str!("../example")), 374);
+    }
+
+    #[test]
+    fn test_part_2_example() {
+        assert_eq!(part_two(include_str!("../example")), 82000210);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Map::from_str("...#\n.#.*").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, "*"));
+        let err = Map::from_str("...#\n.#.").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    #[test]
+    fn test_expansion_examples() {
+        // The puzzle's figures for the example: 1030 when each empty row and column
+        // becomes 10 of them and 8410 when it becomes 100.
+        let map = Map::from_str(include_str!("../example")).unwrap();
+        assert_eq!(sum_of_distances(&map.expand(9)), 1030);
+        assert_eq!(sum_of_distances(&map.expand(99)), 8410);
+    }
+}
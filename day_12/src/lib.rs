@@ -0,0 +1,202 @@
+// Original arrangements solution thanks to: https://github.com/andypymont
+
+use std::{collections::HashMap, str::FromStr};
+
+use aoc_common::{error::parse_number, Answer, ParseError, Solution};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Spring {
+    Damaged,
+    Working,
+    Unknown,
+}
+
+impl From<char> for Spring {
+    fn from(value: char) -> Self {
+        match value {
+            '.' => Self::Working,
+            '#' => Self::Damaged,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Row {
+    springs: Vec<Spring>,
+    damaged_groups: Vec<usize>,
+}
+
+impl FromStr for Row {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut line = s.split_whitespace();
+        let (Some(springs), Some(groups), None) = (line.next(), line.next(), line.next()) else {
+            return Err(
+                ParseError::new(Day12::DAY, "expected springs followed by group sizes")
+                    .at(s, s.trim()),
+            );
+        };
+        if let Some((idx, c)) = springs
+            .char_indices()
+            .find(|(_, c)| !matches!(c, '.' | '#' | '?'))
+        {
+            return Err(ParseError::new(Day12::DAY, "unknown spring condition")
+                .at(s, &springs[idx..idx + c.len_utf8()]));
+        }
+        let springs: Vec<Spring> = springs.chars().map(Spring::from).collect();
+        let damaged_groups: Vec<usize> = groups
+            .split(',')
+            .map(|n| parse_number(Day12::DAY, s, n))
+            .collect::<Result<Vec<usize>, ParseError>>()?;
+        Ok(Row {
+            springs,
+            damaged_groups,
+        })
+    }
+}
+
+impl Row {
+    fn arrangements(&self) -> usize {
+        let mut cache = HashMap::new();
+        self.arrangements_for_section(&mut cache, 0, 0)
+    }
+
+    fn arrangements_for_section(
+        &self,
+        cache: &mut HashMap<(usize, usize), usize>,
+        spring_ix: usize,
+        group_ix: usize,
+    ) -> usize {
+        if let Some(value) = cache.get(&(spring_ix, group_ix)) {
+            return *value;
+        }
+
+        let consume_group = self.damaged_groups.get(group_ix).map_or(0, |group_len| {
+            if (spring_ix + group_len) > self.springs.len() {
+                return 0;
+            }
+
+            if (0..*group_len)
+                .any(|pos| self.springs.get(spring_ix + pos) == Some(&Spring::Working))
+            {
+                return 0;
+            }
+
+            if self.springs.get(spring_ix + group_len) == Some(&Spring::Damaged) {
+                return 0;
+            }
+
+            self.arrangements_for_section(cache, spring_ix + group_len + 1, group_ix + 1)
+        });
+
+        let skip = match self.springs.get(spring_ix) {
+            None => usize::from(group_ix >= self.damaged_groups.len()),
+            Some(Spring::Damaged) => 0,
+            Some(_) => self.arrangements_for_section(cache, spring_ix + 1, group_ix),
+        };
+
+        let arr = consume_group + skip;
+        cache.insert((spring_ix, group_ix), arr);
+        arr
+    }
+
+    fn unfold(&self) -> Self {
+        let mut springs = vec![];
+        let mut damaged_groups = vec![];
+
+        for i in 0..5 {
+            springs.extend(&self.springs);
+            if i != 4 {
+                springs.push(Spring::Unknown);
+            }
+            damaged_groups.extend(&self.damaged_groups);
+        }
+
+        Self {
+            springs,
+            damaged_groups,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Field {
+    rows: Vec<Row>,
+}
+
+impl FromStr for Field {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows = s
+            .lines()
+            .map(|line| Row::from_str(line).map_err(|err| err.within(s, line)))
+            .collect::<Result<Vec<Row>, ParseError>>()?;
+        Ok(Field { rows })
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Field;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Field::from_str(input)
+    }
+
+    fn part_one(field: &Self::Input) -> Answer {
+        field
+            .rows
+            .iter()
+            .map(|r| r.arrangements())
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part_two(field: &Self::Input) -> Answer {
+        field
+            .rows
+            .iter()
+            .map(|r| r.unfold().arrangements())
+            .sum::<usize>()
+            .into()
+    }
+}
+
+pub fn part_one(file: &str) -> usize {
+    let field = Field::from_str(file).unwrap_or_else(|err| panic!("{err}"));
+    field.rows.iter().map(|r| r.arrangements()).sum()
+}
+
+pub fn part_two(file: &str) -> usize {
+    let field = Field::from_str(file).unwrap_or_else(|err| panic!("{err}"));
+    field.rows.iter().map(|r| r.unfold().arrangements()).sum()
+}
+
+#[cfg(test)]
+mod day_12_tests {
+    use crate::*;
+
+    #[test]
+    fn test_part_1_example() {
+        assert_eq!(part_one(include_str!("../example")), 21);
+    }
+
+    #[test]
+    fn test_part_2_example() {
+        assert_eq!(part_two(include_str!("../example")), 525152);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Field::from_str("???.### 1,1,3\n.??..??...?##. 1,x,3").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 18, "x"));
+        let err = Field::from_str("???.#!# 1,1,3").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 6, "!"));
+    }
+}
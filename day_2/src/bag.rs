@@ -0,0 +1,237 @@
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+use aoc_common::{error::parse_number, ParseError, Solution};
+
+use crate::{Day2, Game};
+
+/// How many cubes of each colour the bag holds. Any colour names work; the default is the
+/// puzzle's 12 red, 13 green and 14 blue cubes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bag {
+    limits: BTreeMap<String, u32>,
+}
+
+impl Default for Bag {
+    fn default() -> Self {
+        Bag::new([("red", 12), ("green", 13), ("blue", 14)])
+    }
+}
+
+/// `<count> <colour>` entries separated by commas or newlines, as in
+/// `12 red, 13 green, 14 blue`. Blank lines and `#` comments are ignored so the same
+/// format works for bag files.
+impl FromStr for Bag {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut limits = BTreeMap::new();
+        for entry in s.split([',', '\n']) {
+            let entry = entry.trim();
+            if entry.is_empty() || entry.starts_with('#') {
+                continue;
+            }
+            let Some((count, colour)) = entry
+                .split_once(' ')
+                .filter(|(_, colour)| !colour.contains(' '))
+            else {
+                return Err(ParseError::new(Day2::DAY, "expected `<count> <colour>`").at(s, entry));
+            };
+            let count = parse_number(Day2::DAY, s, count)?;
+            if limits.insert(colour.to_owned(), count).is_some() {
+                return Err(ParseError::new(Day2::DAY, "colour repeated in bag").at(s, colour));
+            }
+        }
+        Ok(Bag { limits })
+    }
+}
+
+impl fmt::Display for Bag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, (colour, count)) in self.limits.iter().enumerate() {
+            if idx > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{count} {colour}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Bag {
+    pub fn new<S: Into<String>>(limits: impl IntoIterator<Item = (S, u32)>) -> Self {
+        let limits = limits
+            .into_iter()
+            .map(|(colour, count)| (colour.into(), count))
+            .collect();
+        Bag { limits }
+    }
+
+    /// The number of `colour` cubes in the bag, or `None` if it has no such colour.
+    pub fn limit(&self, colour: &str) -> Option<u32> {
+        self.limits.get(colour).copied()
+    }
+
+    /// A copy with `delta` more (or fewer) cubes of `colour`, never dropping below zero.
+    pub fn with_delta(&self, colour: &str, delta: i64) -> Bag {
+        let mut bag = self.clone();
+        let count = i64::from(self.limit(colour).unwrap_or(0)) + delta;
+        bag.limits
+            .insert(colour.to_owned(), count.clamp(0, u32::MAX.into()) as u32);
+        bag
+    }
+
+    /// The product of every colour's count, which is the power of a minimum bag, or `None`
+    /// if it overflows a `u64`.
+    pub fn product(&self) -> Option<u64> {
+        self.limits
+            .values()
+            .try_fold(1u64, |product, count| product.checked_mul((*count).into()))
+    }
+
+    pub fn colours(&self) -> impl Iterator<Item = &str> {
+        self.limits.keys().map(String::as_str)
+    }
+
+    /// Whether no draw shows more cubes of a colour than the bag holds. Colours the bag
+    /// doesn't know about are not checked, see [`Bag::unknown_colours`].
+    pub fn allows(&self, game: &Game) -> bool {
+        game.draws.iter().all(|draw| {
+            self.limits
+                .iter()
+                .all(|(colour, limit)| draw.count(colour) <= *limit)
+        })
+    }
+
+    /// Colours a game draws that the bag doesn't have, in alphabetical order.
+    pub fn unknown_colours<'a>(&self, game: &'a Game) -> Vec<&'a str> {
+        game.minimum_bag()
+            .into_keys()
+            .filter(|colour| !self.limits.contains_key(*colour))
+            .collect()
+    }
+
+    /// Strict mode: fails with one line per game that draws a colour the bag doesn't have.
+    pub fn check_colours(&self, games: &[Game]) -> Result<(), String> {
+        let report: Vec<String> = games
+            .iter()
+            .filter_map(|game| {
+                let unknown = self.unknown_colours(game);
+                (!unknown.is_empty()).then(|| {
+                    format!(
+                        "Game {} draws colours the bag doesn't have: {}",
+                        game.id,
+                        unknown.join(", ")
+                    )
+                })
+            })
+            .collect();
+        if report.is_empty() {
+            Ok(())
+        } else {
+            Err(report.join("\n"))
+        }
+    }
+
+    /// Sum of the ids of the games the bag allows, or the id of the game where it
+    /// overflows a `u64`.
+    pub fn sum_of_possible_ids(&self, games: &[Game]) -> Result<u64, u32> {
+        games
+            .iter()
+            .filter(|game| self.allows(game))
+            .try_fold(0u64, |total, game| {
+                total.checked_add(game.id.into()).ok_or(game.id)
+            })
+    }
+
+    /// The product of the fewest cubes of each of the bag's colours that make `game`
+    /// possible, or `None` if it overflows a `u64`. A colour the game never draws makes the
+    /// power 0.
+    pub fn power(&self, game: &Game) -> Option<u64> {
+        let minimum = game.minimum_bag();
+        self.colours().try_fold(1u64, |power, colour| {
+            power.checked_mul(minimum.get(colour).copied().unwrap_or(0).into())
+        })
+    }
+
+    /// Sum of every game's power, or the id of the game where it overflows a `u64`.
+    pub fn sum_of_powers(&self, games: &[Game]) -> Result<u64, u32> {
+        games.iter().try_fold(0u64, |total, game| {
+            self.power(game)
+                .and_then(|power| total.checked_add(power))
+                .ok_or(game.id)
+        })
+    }
+}
+
+#[cfg(test)]
+mod bag_tests {
+    use crate::bag::*;
+
+    fn game(line: &str) -> Game {
+        Game::from_str(line).unwrap()
+    }
+
+    #[test]
+    fn test_parse_bag() {
+        let bag = Bag::from_str("# limits\n2 red, 3 purple\n1 blue\n").unwrap();
+        assert_eq!(bag.to_string(), "1 blue, 3 purple, 2 red");
+        assert_eq!(bag.limit("purple"), Some(3));
+        assert_eq!(bag.limit("green"), None);
+        let err = Bag::from_str("2 red, 3 red").unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (10, "red"));
+        assert!(Bag::from_str("2 red, many blue").is_err());
+    }
+
+    #[test]
+    fn test_arbitrary_colours() {
+        let bag = Bag::from_str("2 red, 3 purple").unwrap();
+        assert!(bag.allows(&game("Game 1: 2 red, 3 purple; 1 red")));
+        assert!(!bag.allows(&game("Game 2: 4 purple")));
+        let games = [
+            game("Game 1: 2 red"),
+            game("Game 2: 4 purple"),
+            game("Game 3: 1 red"),
+        ];
+        assert_eq!(bag.sum_of_possible_ids(&games), Ok(4));
+        assert_eq!(bag.power(&game("Game 1: 2 red, 3 purple; 1 red")), Some(6));
+        assert_eq!(bag.power(&game("Game 1: 2 red")), Some(0));
+        assert_eq!(bag.with_delta("red", -5).limit("red"), Some(0));
+        assert_eq!(bag.with_delta("green", 2).limit("green"), Some(2));
+    }
+
+    #[test]
+    fn test_power_overflow() {
+        let max = u32::MAX;
+        let cubed = game(&format!("Game 1: {max} red, {max} green, {max} blue"));
+        assert_eq!(Bag::default().power(&cubed), None);
+        assert_eq!(Bag::default().sum_of_powers(&[cubed]), Err(1));
+
+        let bag = Bag::from_str("1 red, 1 green").unwrap();
+        let squared = game(&format!("Game 1: {max} red, {max} green"));
+        assert_eq!(bag.power(&squared), Some(u64::from(max) * u64::from(max)));
+        let games = [squared, game(&format!("Game 2: {max} red; {max} green"))];
+        assert_eq!(bag.sum_of_powers(&games), Err(2));
+        assert_eq!(
+            Bag::new([("red", max), ("green", max), ("blue", 2)]).product(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_strict_colours() {
+        let bag = Bag::default();
+        let games = vec![
+            game("Game 1: 3 blue, 4 red"),
+            game("Game 2: 1 teal; 2 pink, 1 teal"),
+        ];
+        assert!(bag.allows(&games[1]));
+        assert_eq!(bag.unknown_colours(&games[1]), vec!["pink", "teal"]);
+        assert_eq!(
+            bag.check_colours(&games),
+            Err(String::from(
+                "Game 2 draws colours the bag doesn't have: pink, teal"
+            ))
+        );
+        assert!(bag.check_colours(&games[..1]).is_ok());
+    }
+}
@@ -0,0 +1,143 @@
+use std::fmt;
+
+use aoc_common::json;
+
+use crate::{Bag, Game};
+
+/// A draw that shows more cubes of a colour than the bag holds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation<'a> {
+    /// One-based position of the draw within its game.
+    pub draw: usize,
+    pub colour: &'a str,
+    pub count: u32,
+    pub limit: u32,
+}
+
+impl Violation<'_> {
+    pub fn excess(&self) -> u32 {
+        self.count - self.limit
+    }
+}
+
+/// Every reason one game is impossible with a given bag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Impossible<'a> {
+    pub game: u32,
+    pub violations: Vec<Violation<'a>>,
+}
+
+impl Impossible<'_> {
+    /// One JSON object on a single line.
+    pub fn to_json(&self) -> String {
+        let violations: Vec<String> = self
+            .violations
+            .iter()
+            .map(|violation| {
+                format!(
+                    "{{\"draw\":{},\"colour\":{},\"count\":{},\"limit\":{},\"excess\":{}}}",
+                    violation.draw,
+                    json::string(violation.colour),
+                    violation.count,
+                    violation.limit,
+                    violation.excess()
+                )
+            })
+            .collect();
+        format!(
+            "{{\"game\":{},\"violations\":[{}]}}",
+            self.game,
+            violations.join(",")
+        )
+    }
+}
+
+impl fmt::Display for Impossible<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Game {} is impossible:", self.game)?;
+        for violation in self.violations.iter() {
+            write!(
+                f,
+                "\n  draw {}: {} {}, {} more than the {} in the bag",
+                violation.draw,
+                violation.count,
+                violation.colour,
+                violation.excess(),
+                violation.limit
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Every draw of `game` that exceeds the bag, in draw order.
+pub fn violations<'a>(game: &'a Game, bag: &Bag) -> Vec<Violation<'a>> {
+    game.draws
+        .iter()
+        .enumerate()
+        .flat_map(|(idx, draw)| {
+            draw.cubes().filter_map(move |(colour, count)| {
+                let limit = bag.limit(colour)?;
+                (count > limit).then_some(Violation {
+                    draw: idx + 1,
+                    colour,
+                    count,
+                    limit,
+                })
+            })
+        })
+        .collect()
+}
+
+/// The games the bag doesn't allow, each with the draws that rule it out.
+pub fn impossible_games<'a>(games: &'a [Game], bag: &Bag) -> Vec<Impossible<'a>> {
+    games
+        .iter()
+        .map(|game| Impossible {
+            game: game.id,
+            violations: violations(game, bag),
+        })
+        .filter(|impossible| !impossible.violations.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod explain_tests {
+    use std::str::FromStr;
+
+    use aoc_common::Solution;
+
+    use crate::explain::*;
+
+    #[test]
+    fn test_explain_impossible_game() {
+        let games = vec![
+            Game::from_str("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green").unwrap(),
+            Game::from_str("Game 4: 1 green, 3 red").unwrap(),
+        ];
+        let impossible = impossible_games(&games, &Bag::default());
+        assert_eq!(impossible.len(), 1);
+        assert_eq!(
+            impossible[0].to_string(),
+            "Game 3 is impossible:\n  draw 1: 20 red, 8 more than the 12 in the bag"
+        );
+        assert_eq!(
+            impossible[0].to_json(),
+            r#"{"game":3,"violations":[{"draw":1,"colour":"red","count":20,"limit":12,"excess":8}]}"#
+        );
+    }
+
+    #[test]
+    fn test_matches_part_one() {
+        let games = crate::Day2::parse(include_str!("../example")).unwrap();
+        let bag = Bag::default();
+        let impossible: Vec<u32> = impossible_games(&games, &bag)
+            .iter()
+            .map(|impossible| impossible.game)
+            .collect();
+        assert_eq!(impossible, vec![3, 4]);
+        assert!(games
+            .iter()
+            .all(|game| bag.allows(game) != impossible.contains(&game.id)));
+    }
+}
@@ -0,0 +1,179 @@
+mod bag;
+pub mod explain;
+pub mod query;
+
+use std::{collections::BTreeMap, str::FromStr};
+
+use aoc_common::{error::parse_number, Answer, ParseError, Solution};
+
+pub use bag::Bag;
+
+const PREFIX: &str = "Game ";
+
+/// One handful of cubes shown from the bag, as a count per colour.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Draw {
+    cubes: BTreeMap<String, u32>,
+}
+
+impl FromStr for Draw {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cubes = BTreeMap::new();
+        for cube in s.split(',') {
+            let cube = cube.trim();
+            let Some((count, colour)) = cube
+                .split_once(' ')
+                .filter(|(_, colour)| !colour.is_empty() && !colour.contains(' '))
+            else {
+                return Err(ParseError::new(Day2::DAY, "expected `<count> <colour>`").at(s, cube));
+            };
+            let count = parse_number(Day2::DAY, s, count)?;
+            if cubes.insert(colour.to_owned(), count).is_some() {
+                return Err(ParseError::new(Day2::DAY, "colour repeated in one draw").at(s, colour));
+            }
+        }
+        Ok(Draw { cubes })
+    }
+}
+
+impl Draw {
+    pub fn count(&self, colour: &str) -> u32 {
+        self.cubes.get(colour).copied().unwrap_or(0)
+    }
+
+    pub fn cubes(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.cubes
+            .iter()
+            .map(|(colour, count)| (colour.as_str(), *count))
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub draws: Vec<Draw>,
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (id, draws) = s
+            .strip_prefix(PREFIX)
+            .and_then(|rest| rest.split_once(':'))
+            .ok_or_else(|| {
+                ParseError::new(Day2::DAY, "expected `Game <id>: <draws>`").at(s, s.trim())
+            })?;
+        let draws = draws
+            .split(';')
+            .map(|draw| Draw::from_str(draw).map_err(|err| err.within(s, draw)))
+            .collect::<Result<Vec<Draw>, ParseError>>()?;
+        let res = Game {
+            id: parse_number(Day2::DAY, s, id)?,
+            draws,
+        };
+        Ok(res)
+    }
+}
+
+impl Game {
+    /// The fewest cubes of each colour the bag must hold for every draw to be possible.
+    pub fn minimum_bag(&self) -> BTreeMap<&str, u32> {
+        let mut minimum: BTreeMap<&str, u32> = BTreeMap::new();
+        for (colour, count) in self.draws.iter().flat_map(Draw::cubes) {
+            let min = minimum.entry(colour).or_default();
+            *min = (*min).max(count);
+        }
+        minimum
+    }
+}
+
+fn parse_games(s: &str) -> Result<Vec<Game>, ParseError> {
+    s.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| Game::from_str(line).map_err(|err| err.within(s, line)))
+        .collect()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_games(input)
+    }
+
+    fn part_one(games: &Self::Input) -> Answer {
+        sum_of_possible_ids(&Bag::default(), games).into()
+    }
+
+    fn part_two(games: &Self::Input) -> Answer {
+        sum_of_powers(&Bag::default(), games).into()
+    }
+}
+
+fn sum_of_possible_ids(bag: &Bag, games: &[Game]) -> u64 {
+    bag.sum_of_possible_ids(games)
+        .unwrap_or_else(|id| panic!("Sum of possible ids overflows u64 at game {id}"))
+}
+
+fn sum_of_powers(bag: &Bag, games: &[Game]) -> u64 {
+    bag.sum_of_powers(games)
+        .unwrap_or_else(|id| panic!("Sum of powers overflows u64 at game {id}"))
+}
+
+pub fn part_one(file: String) -> u64 {
+    let games = parse_games(&file).unwrap_or_else(|err| panic!("{err}"));
+    sum_of_possible_ids(&Bag::default(), &games)
+}
+
+pub fn part_two(file: String) -> u64 {
+    let games = parse_games(&file).unwrap_or_else(|err| panic!("{err}"));
+    sum_of_powers(&Bag::default(), &games)
+}
+
+#[cfg(test)]
+mod day_2_tests {
+    use crate::*;
+
+    #[test]
+    fn test_part_1_example() {
+        assert_eq!(part_one(include_str!("../example").to_owned()), 8);
+    }
+
+    #[test]
+    fn test_part_2_example() {
+        assert_eq!(part_two(include_str!("../example").to_owned()), 2286);
+    }
+
+    #[test]
+    fn test_parse_game() {
+        let game = Game::from_str("Game 12: 3 blue, 4 red; 2 green").unwrap();
+        assert_eq!(game.id, 12);
+        assert_eq!(game.draws.len(), 2);
+        assert_eq!(game.draws[0].count("red"), 4);
+        assert_eq!(game.draws[1].count("red"), 0);
+        assert_eq!(
+            game.minimum_bag(),
+            BTreeMap::from([("blue", 3), ("green", 2), ("red", 4)])
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = parse_games("Game 1: 3 blue\nGame 2: 1 red, x green").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 16, "x"));
+        let err = parse_games("Game 1: 3 blue; 4 red, 2 red").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 26, "red"));
+        let err = parse_games("Game one: 3 blue").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 6, "one"));
+        assert!(parse_games("Game 1 3 blue").is_err());
+        assert!(parse_games("Game 1: 3 blue;").is_err());
+        assert!(parse_games("Game 1: 3 dark blue").is_err());
+    }
+}
@@ -1,104 +1,183 @@
-use std::collections::HashMap;
+use std::str::FromStr;
 
-const PREFIX: &str = "Game ";
+use aoc_common::{
+    cli::{self, Args},
+    input::InputSource,
+    Solution,
+};
+use day_2::{explain, query, Bag, Day2, Game};
 
 fn main() {
-    let file = include_str!("../input").to_owned();
-    println!("Part 1: {}", part_one(String::from(&file)));
-    println!("Part 2: {}", part_two(file));
+    cli::exit_on_error(run(Args::from_env()));
 }
 
-pub fn part_one(file: String) -> u32 {
-    let limits: HashMap<&str, u32> = HashMap::from([("red", 12), ("green", 13), ("blue", 14)]);
+const USAGE: &str = "Usage:
+    day_2 [--input <path>|-] [--bag <counts>|--bag-file <path>] [--strict]
+    day_2 --explain [--format text|json] [--input <path>|-] [--bag <counts>|--bag-file <path>]
+    day_2 query possible [--delta <colour>=<n>,...] [--input <path>|-] [--bag <counts>|--bag-file <path>]
+    day_2 query minimal-bag [--input <path>|-]
+    day_2 query stats [--input <path>|-]";
 
-    let possible_games = file
-        .lines()
-        .map(|line| {
-            let id: u32 = line
-                .split(":")
-                .next()
-                .unwrap_or("")
-                .replace(PREFIX, "")
-                .parse()
-                .unwrap_or(0);
-            let cubes: Vec<&str> = line.split(":").last().unwrap_or("").split(";").collect();
-            for turn in cubes.iter() {
-                let turn: Vec<bool> = turn
-                    .split(",")
-                    .map(|set| {
-                        let set = set.trim();
-                        let num: u32 = set
-                            .split(" ")
-                            .next()
-                            .unwrap()
-                            .parse()
-                            .expect("Failed to parse u32");
-                        let color = set.split(" ").last().unwrap_or("");
+/// Solves both parts against the puzzle's bag unless another one is given with
+/// `--bag "<count> <colour>, ..."` or `--bag-file <path>`. `--strict` fails on games that
+/// draw colours the bag doesn't have instead of ignoring those colours. `--explain` lists
+/// every draw that makes a game impossible instead, as text or as one JSON object per game.
+fn run(mut args: Args) -> Result<(), String> {
+    if let Some(command) = args.subcommand() {
+        if command != "query" {
+            return Err(format!("Unknown command `{command}`\n{USAGE}"));
+        }
+        return run_query(args);
+    }
+    let bag = parse_bag(&mut args)?;
+    let strict = args.flag("--strict");
+    let explain = args.flag("--explain");
+    let json = match args.value("--format")?.as_deref() {
+        None | Some("text") => false,
+        Some("json") => true,
+        Some(other) => return Err(format!("Unknown format `{other}`, expected text or json")),
+    };
+    if json && !explain {
+        return Err(String::from("--format only applies to --explain"));
+    }
+    if bag.is_none() && !strict && !explain {
+        return cli::try_run::<Day2>(args);
+    }
+    let bag = bag.unwrap_or_default();
+    let source = InputSource::from_arg(args.value("--input")?.as_deref(), Day2::DAY);
+    args.finish()?;
 
-                        if let Some(limit) = limits.get(color) {
-                            if limit < &num {
-                                return false;
-                            }
-                        }
-                        true
-                    })
-                    .collect();
-                if turn.iter().any(|e| e == &false) {
-                    return 0;
-                }
+    let input = source.read().map_err(|err| err.to_string())?;
+    let games = Day2::parse(&input).map_err(|err| format!("Failed to parse `{source}`: {err}"))?;
+    if strict {
+        bag.check_colours(&games)?;
+    }
+    if explain {
+        let impossible = explain::impossible_games(&games, &bag);
+        for game in impossible.iter() {
+            if json {
+                println!("{}", game.to_json());
+            } else {
+                println!("{game}");
             }
-            id
-        })
-        .sum();
-
-    possible_games
+        }
+        if !json {
+            println!("Impossible games: {} of {}", impossible.len(), games.len());
+        }
+        return Ok(());
+    }
+    let ids = bag
+        .sum_of_possible_ids(&games)
+        .map_err(|id| format!("Sum of possible ids overflows u64 at game {id}"))?;
+    println!("Part 1: {ids}");
+    let powers = bag
+        .sum_of_powers(&games)
+        .map_err(|id| format!("Sum of powers overflows u64 at game {id}"))?;
+    println!("Part 2: {powers}");
+    Ok(())
 }
 
-pub fn part_two(file: String) -> u32 {
-    let power = file
-        .lines()
-        .map(|line| {
-            let cubes: Vec<&str> = line.split(":").last().unwrap_or("").split(";").collect();
-            let mut minimum: HashMap<&str, u32> =
-                HashMap::from([("red", 0), ("green", 0), ("blue", 0)]);
+/// Answers one question about the games without changing the puzzle code.
+fn run_query(mut args: Args) -> Result<(), String> {
+    let query = args.subcommand();
+    // Only `possible` looks at a bag, so the other queries reject its flags as unknown.
+    let (bag, delta) = if query.as_deref() == Some("possible") {
+        (
+            parse_bag(&mut args)?.unwrap_or_default(),
+            args.value("--delta")?,
+        )
+    } else {
+        (Bag::default(), None)
+    };
+    let source = InputSource::from_arg(args.value("--input")?.as_deref(), Day2::DAY);
+    args.finish()?;
 
-            for turn in cubes.iter() {
-                turn.split(",").for_each(|set| {
-                    let set = set.trim();
-                    let num: u32 = set
-                        .split(" ")
-                        .next()
-                        .unwrap()
-                        .parse()
-                        .expect("Failed to parse u32");
-                    let color = set.split(" ").last().unwrap_or("");
-
-                    if let Some(min) = minimum.get(color) {
-                        if &num > min {
-                            minimum.insert(color, num);
-                        }
-                    }
-                });
+    let input = source.read().map_err(|err| err.to_string())?;
+    let games = Day2::parse(&input).map_err(|err| format!("Failed to parse `{source}`: {err}"))?;
+    match query.as_deref() {
+        Some("possible") => {
+            let mut changed = bag.clone();
+            if let Some(delta) = delta {
+                for (colour, delta) in parse_deltas(&delta)? {
+                    changed = changed.with_delta(&colour, delta);
+                }
             }
-            let tot = minimum.iter().fold(1, |acc, (_, num)| acc * num);
-            return tot;
-        })
-        .sum();
-
-    power
+            println!("Bag: {changed}");
+            let possible = query::possible_games(&games, &changed);
+            let sum = changed
+                .sum_of_possible_ids(&games)
+                .map_err(|id| format!("Sum of possible ids overflows u64 at game {id}"))?;
+            println!("Possible games: {} (sum of ids {sum})", ids(&possible));
+            if changed != bag {
+                println!(
+                    "Newly possible: {}",
+                    ids(&query::newly_possible(&games, &bag, &changed))
+                );
+            }
+        }
+        Some("minimal-bag") => {
+            let minimal = query::minimal_bag(&games);
+            println!("Minimal bag: {minimal}");
+            let power = minimal
+                .product()
+                .ok_or("Power of the minimal bag overflows u64")?;
+            println!("Power: {power}");
+        }
+        Some("stats") => {
+            println!(
+                "{:<10}  {:>5}  {:>5}  {:>6}",
+                "Colour", "Draws", "Max", "Mean"
+            );
+            for stats in query::colour_stats(&games) {
+                println!(
+                    "{:<10}  {:>5}  {:>5}  {:>6.2}",
+                    stats.colour, stats.draws, stats.max, stats.mean
+                );
+            }
+        }
+        Some(other) => return Err(format!("Unknown query `{other}`\n{USAGE}")),
+        None => return Err(format!("Missing query\n{USAGE}")),
+    }
+    Ok(())
 }
 
-#[cfg(test)]
-mod day_2_tests {
-    use crate::*;
-
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_one(include_str!("../example").to_owned()), 8);
+fn ids(games: &[&Game]) -> String {
+    if games.is_empty() {
+        return String::from("none");
     }
+    let ids: Vec<String> = games.iter().map(|game| game.id.to_string()).collect();
+    ids.join(", ")
+}
+
+/// Parses `blue=2,red=-1` into colour and delta pairs.
+fn parse_deltas(spec: &str) -> Result<Vec<(String, i64)>, String> {
+    spec.split(',')
+        .map(|entry| {
+            let (colour, delta) = entry
+                .split_once('=')
+                .ok_or_else(|| format!("Expected `<colour>=<n>`, found `{entry}`"))?;
+            let delta = delta
+                .trim_start_matches('+')
+                .parse()
+                .map_err(|_| format!("Invalid delta `{delta}` for {colour}"))?;
+            Ok((colour.to_owned(), delta))
+        })
+        .collect()
+}
 
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(part_two(include_str!("../example").to_owned()), 2286);
+fn parse_bag(args: &mut Args) -> Result<Option<Bag>, String> {
+    match (args.value("--bag")?, args.value("--bag-file")?) {
+        (Some(_), Some(_)) => Err(String::from("--bag and --bag-file can't be used together")),
+        (Some(spec), None) => Bag::from_str(&spec)
+            .map(Some)
+            .map_err(|err| format!("Invalid --bag: {err}")),
+        (None, Some(path)) => {
+            let source = InputSource::File(path.into());
+            let text = source.read().map_err(|err| err.to_string())?;
+            Bag::from_str(&text)
+                .map(Some)
+                .map_err(|err| format!("Failed to parse `{source}`: {err}"))
+        }
+        (None, None) => Ok(None),
     }
 }
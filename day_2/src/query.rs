@@ -0,0 +1,107 @@
+use std::collections::BTreeMap;
+
+use crate::{Bag, Game};
+
+/// How often a colour is drawn across every draw of every game.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColourStats {
+    pub colour: String,
+    /// Draws that show at least one cube of the colour.
+    pub draws: usize,
+    pub max: u32,
+    /// Mean over all draws, counting draws without the colour as 0.
+    pub mean: f64,
+}
+
+/// Games the bag allows, in input order.
+pub fn possible_games<'a>(games: &'a [Game], bag: &Bag) -> Vec<&'a Game> {
+    games.iter().filter(|game| bag.allows(game)).collect()
+}
+
+/// Games that `changed` allows but `bag` doesn't, e.g. after adding two blue cubes.
+pub fn newly_possible<'a>(games: &'a [Game], bag: &Bag, changed: &Bag) -> Vec<&'a Game> {
+    games
+        .iter()
+        .filter(|game| !bag.allows(game) && changed.allows(game))
+        .collect()
+}
+
+/// The smallest bag that makes every game possible: each game's minimum bag, as used for
+/// part two, merged by taking the largest count per colour.
+pub fn minimal_bag(games: &[Game]) -> Bag {
+    let mut minimal: BTreeMap<&str, u32> = BTreeMap::new();
+    for (colour, count) in games.iter().flat_map(Game::minimum_bag) {
+        let max = minimal.entry(colour).or_default();
+        *max = (*max).max(count);
+    }
+    Bag::new(minimal)
+}
+
+/// Statistics for every colour drawn, in alphabetical order.
+pub fn colour_stats(games: &[Game]) -> Vec<ColourStats> {
+    let draws: Vec<_> = games.iter().flat_map(|game| game.draws.iter()).collect();
+    let mut counts: BTreeMap<&str, Vec<u32>> = BTreeMap::new();
+    for (colour, count) in draws.iter().flat_map(|draw| draw.cubes()) {
+        counts.entry(colour).or_default().push(count);
+    }
+    counts
+        .into_iter()
+        .map(|(colour, counts)| ColourStats {
+            colour: colour.to_owned(),
+            draws: counts.len(),
+            max: counts.iter().copied().max().unwrap_or(0),
+            mean: counts.iter().map(|count| f64::from(*count)).sum::<f64>() / draws.len() as f64,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod query_tests {
+    use std::str::FromStr;
+
+    use crate::query::*;
+
+    fn games() -> Vec<Game> {
+        ["Game 1: 3 blue, 4 red; 2 green", "Game 2: 15 blue; 1 red"]
+            .iter()
+            .map(|line| Game::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_possible_with_delta() {
+        let games = games();
+        let bag = Bag::default();
+        assert_eq!(possible_games(&games, &bag).len(), 1);
+        let changed = bag.with_delta("blue", 1);
+        let newly: Vec<u32> = newly_possible(&games, &bag, &changed)
+            .iter()
+            .map(|game| game.id)
+            .collect();
+        assert_eq!(newly, vec![2]);
+    }
+
+    #[test]
+    fn test_minimal_bag() {
+        let minimal = minimal_bag(&games());
+        assert_eq!(minimal.to_string(), "15 blue, 2 green, 4 red");
+        assert_eq!(minimal.product(), Some(120));
+        assert!(games().iter().all(|game| minimal.allows(game)));
+    }
+
+    #[test]
+    fn test_colour_stats() {
+        let stats = colour_stats(&games());
+        assert_eq!(stats.len(), 3);
+        assert_eq!(
+            stats[0],
+            ColourStats {
+                colour: String::from("blue"),
+                draws: 2,
+                max: 15,
+                mean: 4.5,
+            }
+        );
+        assert_eq!((stats[2].colour.as_str(), stats[2].mean), ("red", 1.25));
+    }
+}
@@ -0,0 +1,74 @@
+pub mod render;
+mod rules;
+mod schematic;
+
+use aoc_common::{Answer, ParseError, Solution};
+
+pub use rules::{Aggregation, Arity, GearRule, Rules};
+pub use schematic::{is_symbol, Gear, Number, Schematic, Symbol};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = Schematic;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Schematic::parse(input)
+    }
+
+    fn part_one(schematic: &Self::Input) -> Answer {
+        sum_of_parts(schematic).into()
+    }
+
+    fn part_two(schematic: &Self::Input) -> Answer {
+        sum_of_ratios(schematic).into()
+    }
+}
+
+fn sum_of_parts(schematic: &Schematic) -> u64 {
+    schematic
+        .sum_of_parts()
+        .unwrap_or_else(|(x, y)| panic!("Sum of part numbers overflows u64 at ({x}, {y})"))
+}
+
+fn sum_of_ratios(schematic: &Schematic) -> u64 {
+    schematic
+        .sum_of_ratios()
+        .unwrap_or_else(|(x, y)| panic!("Sum of gear ratios overflows u64 at ({x}, {y})"))
+}
+
+pub fn part_one(file: String) -> u64 {
+    let schematic = Day3::parse(&file).unwrap_or_else(|err| panic!("{err}"));
+    sum_of_parts(&schematic)
+}
+
+pub fn part_two(file: String) -> u64 {
+    let schematic = Day3::parse(&file).unwrap_or_else(|err| panic!("{err}"));
+    sum_of_ratios(&schematic)
+}
+
+#[cfg(test)]
+mod day_3_tests {
+    use crate::*;
+
+    #[test]
+    fn test_part_1_example() {
+        assert_eq!(part_one(include_str!("../example").to_owned()), 4361);
+    }
+
+    #[test]
+    fn test_part_2_example() {
+        assert_eq!(part_two(include_str!("../example").to_owned()), 467835);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day3::parse("467..\n...*").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        let digits = "9".repeat(20);
+        let err = Day3::parse(&format!("{}\n{digits}", ".".repeat(20))).unwrap_err();
+        assert_eq!((err.line, err.column, err.text), (2, 1, digits));
+    }
+}
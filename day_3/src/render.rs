@@ -0,0 +1,190 @@
+use std::{fmt::Write, str::FromStr};
+
+use crate::{is_symbol, Gear, Rules, Schematic};
+
+/// How a cell of the schematic is highlighted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Highlight {
+    Blank,
+    /// A digit of a number next to a symbol.
+    Part,
+    /// A digit of a number no symbol touches.
+    Orphan,
+    Gear,
+    Symbol,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    Ansi,
+    Html,
+}
+
+impl FromStr for Style {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ansi" => Ok(Style::Ansi),
+            "html" => Ok(Style::Html),
+            _ => Err(format!("Unknown style `{s}`, expected ansi or html")),
+        }
+    }
+}
+
+impl Style {
+    fn open(self, highlight: Highlight) -> &'static str {
+        match (self, highlight) {
+            (_, Highlight::Blank) => "",
+            (Style::Ansi, Highlight::Part) => "\x1b[32m",
+            (Style::Ansi, Highlight::Orphan) => "\x1b[31m",
+            (Style::Ansi, Highlight::Gear) => "\x1b[1;33m",
+            (Style::Ansi, Highlight::Symbol) => "\x1b[36m",
+            (Style::Html, Highlight::Part) => "<span class=\"part\">",
+            (Style::Html, Highlight::Orphan) => "<span class=\"orphan\">",
+            (Style::Html, Highlight::Gear) => "<span class=\"gear\">",
+            (Style::Html, Highlight::Symbol) => "<span class=\"symbol\">",
+        }
+    }
+
+    fn close(self, highlight: Highlight) -> &'static str {
+        match (self, highlight) {
+            (_, Highlight::Blank) => "",
+            (Style::Ansi, _) => "\x1b[0m",
+            (Style::Html, _) => "</span>",
+        }
+    }
+
+    fn escape(self, c: char, out: &mut String) {
+        match (self, c) {
+            (Style::Html, '<') => out.push_str("&lt;"),
+            (Style::Html, '>') => out.push_str("&gt;"),
+            (Style::Html, '&') => out.push_str("&amp;"),
+            _ => out.push(c),
+        }
+    }
+}
+
+const HTML_STYLE: &str = "<style>
+  .part { color: green; }
+  .orphan { color: red; }
+  .gear { color: orange; font-weight: bold; }
+  .symbol { color: teal; }
+  .ratio { color: grey; }
+</style>";
+
+/// The highlight of every cell, by row and then column.
+pub fn highlights(schematic: &Schematic, gears: &[Gear<'_>]) -> Vec<Vec<Highlight>> {
+    let grid = schematic.grid();
+    let mut highlights: Vec<Vec<Highlight>> = grid
+        .rows()
+        .map(|row| {
+            row.iter()
+                .map(|c| {
+                    if is_symbol(*c) {
+                        Highlight::Symbol
+                    } else {
+                        Highlight::Blank
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    for number in schematic.numbers() {
+        for (x, y) in number.cells() {
+            highlights[y][x] = Highlight::Orphan;
+        }
+    }
+    for part in schematic.parts() {
+        for (x, y) in part.cells() {
+            highlights[y][x] = Highlight::Part;
+        }
+    }
+    for gear in gears.iter() {
+        highlights[gear.symbol.y][gear.symbol.x] = Highlight::Gear;
+    }
+    highlights
+}
+
+/// Re-draws the schematic with parts, orphan numbers, gears and other symbols highlighted.
+/// Each row with gears on it ends with their numbers and score, e.g. `* (467, 35) = 16345`.
+/// Fails with the position of a gear whose score doesn't fit in `u64`.
+pub fn render(
+    schematic: &Schematic,
+    rules: &Rules,
+    style: Style,
+) -> Result<String, (usize, usize)> {
+    let gears = schematic.gears(rules)?;
+    let highlights = highlights(schematic, &gears);
+    let mut out = String::new();
+    if style == Style::Html {
+        out.push_str(HTML_STYLE);
+        out.push_str("\n<pre class=\"schematic\">\n");
+    }
+    for (y, cells) in schematic.grid().rows().enumerate() {
+        let mut current = Highlight::Blank;
+        for (c, highlight) in cells.iter().zip(highlights[y].iter()) {
+            if *highlight != current {
+                out.push_str(style.close(current));
+                out.push_str(style.open(*highlight));
+                current = *highlight;
+            }
+            style.escape(*c, &mut out);
+        }
+        out.push_str(style.close(current));
+        for gear in gears.iter().filter(|gear| gear.symbol.y == y) {
+            let values: Vec<String> = gear
+                .numbers
+                .iter()
+                .map(|number| number.value.to_string())
+                .collect();
+            out.push_str("  ");
+            if style == Style::Html {
+                out.push_str("<span class=\"ratio\">");
+            }
+            style.escape(gear.symbol.c, &mut out);
+            let _ = write!(out, " ({}) = {}", values.join(", "), gear.score);
+            if style == Style::Html {
+                out.push_str("</span>");
+            }
+        }
+        out.push('\n');
+    }
+    if style == Style::Html {
+        out.push_str("</pre>\n");
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod render_tests {
+    use aoc_common::Solution;
+
+    use crate::{render::*, Day3};
+
+    #[test]
+    fn test_highlights() {
+        let schematic = Day3::parse("467..\n...*.\n..35#\n7....").unwrap();
+        let gears = schematic.gears(&Rules::default()).unwrap();
+        let highlights = highlights(&schematic, &gears);
+        assert_eq!(highlights[0][0], Highlight::Part);
+        assert_eq!(highlights[0][3], Highlight::Blank);
+        assert_eq!(highlights[1][3], Highlight::Gear);
+        assert_eq!(highlights[2][4], Highlight::Symbol);
+        assert_eq!(highlights[3][0], Highlight::Orphan);
+    }
+
+    #[test]
+    fn test_render() {
+        let schematic = Day3::parse("467..\n...*.\n&.35.\n....7").unwrap();
+        let ansi = render(&schematic, &Rules::default(), Style::Ansi).unwrap();
+        let lines: Vec<&str> = ansi.lines().collect();
+        assert_eq!(lines[0], "\x1b[32m467\x1b[0m..");
+        assert_eq!(lines[1], "...\x1b[1;33m*\x1b[0m.  * (467, 35) = 16345");
+        assert_eq!(lines[3], "....\x1b[31m7\x1b[0m");
+        let html = render(&schematic, &Rules::default(), Style::Html).unwrap();
+        assert!(html.contains("<span class=\"symbol\">&amp;</span>"));
+        assert!(html.contains("<span class=\"ratio\">* (467, 35) = 16345</span>"));
+        assert!(html.ends_with("</pre>\n"));
+    }
+}
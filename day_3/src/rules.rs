@@ -0,0 +1,228 @@
+use std::{collections::BTreeMap, fmt, str::FromStr};
+
+use aoc_common::{error::parse_number, ParseError, Solution};
+
+use crate::{is_symbol, Day3};
+
+/// How many numbers a symbol must touch for its rule to apply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Arity {
+    Exactly(usize),
+    AtLeast(usize),
+}
+
+impl Arity {
+    pub fn accepts(self, count: usize) -> bool {
+        match self {
+            Arity::Exactly(n) => count == n,
+            Arity::AtLeast(n) => count >= n,
+        }
+    }
+}
+
+/// How the touching numbers combine into the symbol's score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregation {
+    Product,
+    Sum,
+    Max,
+}
+
+impl Aggregation {
+    /// Combines `values`, or `None` when the result doesn't fit in `u64`.
+    fn apply(self, values: &[u64]) -> Option<u64> {
+        let mut values = values.iter().copied();
+        match self {
+            Aggregation::Product => values.try_fold(1u64, u64::checked_mul),
+            Aggregation::Sum => values.try_fold(0u64, u64::checked_add),
+            Aggregation::Max => Some(values.max().unwrap_or(0)),
+        }
+    }
+}
+
+impl FromStr for Aggregation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "product" => Ok(Aggregation::Product),
+            "sum" => Ok(Aggregation::Sum),
+            "max" => Ok(Aggregation::Max),
+            _ => Err(String::from("expected product, sum or max")),
+        }
+    }
+}
+
+impl fmt::Display for Aggregation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Aggregation::Product => "product",
+            Aggregation::Sum => "sum",
+            Aggregation::Max => "max",
+        };
+        write!(f, "{name}")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GearRule {
+    pub arity: Arity,
+    pub aggregation: Aggregation,
+}
+
+/// A gear score too large for `u64`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow;
+
+impl GearRule {
+    /// The score of a symbol touching `values`, or `None` when the arity doesn't match.
+    pub fn score(&self, values: &[u64]) -> Result<Option<u64>, Overflow> {
+        if !self.arity.accepts(values.len()) {
+            return Ok(None);
+        }
+        self.aggregation.apply(values).ok_or(Overflow).map(Some)
+    }
+}
+
+/// The gear rule for each symbol character. Symbols without a rule never score. The
+/// default is the puzzle's: a `*` touching exactly two numbers scores their product.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    rules: BTreeMap<char, GearRule>,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules::new([(
+            '*',
+            GearRule {
+                arity: Arity::Exactly(2),
+                aggregation: Aggregation::Product,
+            },
+        )])
+    }
+}
+
+/// One `<symbol> <count> <aggregation>` entry per line, where the count is `N` for exactly
+/// N numbers or `N+` for at least N, as in `# 3+ sum`. The symbol is any character the
+/// schematic reads as one, see [`is_symbol`], including `,`. Blank lines and lines
+/// starting with `//` are ignored, since `#` is a symbol here.
+impl FromStr for Rules {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rules = BTreeMap::new();
+        for entry in s.lines() {
+            let entry = entry.trim();
+            if entry.is_empty() || entry.starts_with("//") {
+                continue;
+            }
+            let fields: Vec<&str> = entry.split_whitespace().collect();
+            let [symbol, count, aggregation] = fields[..] else {
+                return Err(ParseError::new(
+                    Day3::DAY,
+                    "expected `<symbol> <count> <aggregation>`",
+                )
+                .at(s, entry));
+            };
+            let mut chars = symbol.chars();
+            let (Some(c), None) = (chars.next(), chars.next()) else {
+                return Err(ParseError::new(Day3::DAY, "expected one symbol").at(s, symbol));
+            };
+            if !is_symbol(c) {
+                return Err(ParseError::new(Day3::DAY, "not a schematic symbol").at(s, symbol));
+            }
+            let arity = match count.strip_suffix('+') {
+                Some(n) => Arity::AtLeast(parse_number(Day3::DAY, s, n)?),
+                None => Arity::Exactly(parse_number(Day3::DAY, s, count)?),
+            };
+            let aggregation = aggregation
+                .parse()
+                .map_err(|err: String| ParseError::new(Day3::DAY, err).at(s, aggregation))?;
+            let rule = GearRule { arity, aggregation };
+            if rules.insert(c, rule).is_some() {
+                return Err(ParseError::new(Day3::DAY, "symbol repeated in rules").at(s, symbol));
+            }
+        }
+        Ok(Rules { rules })
+    }
+}
+
+impl fmt::Display for Rules {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, (c, rule)) in self.rules.iter().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            match rule.arity {
+                Arity::Exactly(n) => write!(f, "{c} {n} {}", rule.aggregation)?,
+                Arity::AtLeast(n) => write!(f, "{c} {n}+ {}", rule.aggregation)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Rules {
+    pub fn new(rules: impl IntoIterator<Item = (char, GearRule)>) -> Self {
+        Rules {
+            rules: rules.into_iter().collect(),
+        }
+    }
+
+    pub fn get(&self, c: char) -> Option<&GearRule> {
+        self.rules.get(&c)
+    }
+}
+
+#[cfg(test)]
+mod rules_tests {
+    use crate::rules::*;
+
+    #[test]
+    fn test_parse_rules() {
+        let rules = Rules::from_str("// gears\n* 2 product\n\n# 3+ sum\n, 1 max").unwrap();
+        assert_eq!(rules.to_string(), "# 3+ sum\n* 2 product\n, 1 max");
+        assert_eq!(Rules::from_str(&rules.to_string()), Ok(rules.clone()));
+        assert_eq!(
+            rules.get('#'),
+            Some(&GearRule {
+                arity: Arity::AtLeast(3),
+                aggregation: Aggregation::Sum,
+            })
+        );
+        assert_eq!(
+            rules.get(',').map(|rule| rule.arity),
+            Some(Arity::Exactly(1))
+        );
+        assert_eq!(rules.get('+'), None);
+        assert_eq!(Rules::from_str("* 2 product").unwrap(), Rules::default());
+        let err = Rules::from_str("* 2 product\n** 2 sum").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "**"));
+        let err = Rules::from_str("* 2 mean").unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (5, "mean"));
+        assert!(Rules::from_str("* two product").is_err());
+        assert!(Rules::from_str("* 2 sum\n* 3 sum").is_err());
+        assert!(Rules::from_str("* 2 product, # 3+ sum").is_err());
+        for symbol in ["7", ".", "x"] {
+            let err = Rules::from_str(&format!("* 2 product\n{symbol} 2 sum")).unwrap_err();
+            assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, symbol));
+        }
+    }
+
+    #[test]
+    fn test_score() {
+        let rule = |arity, aggregation| GearRule { arity, aggregation };
+        let product = rule(Arity::Exactly(2), Aggregation::Product);
+        assert_eq!(product.score(&[3, 4]), Ok(Some(12)));
+        assert_eq!(product.score(&[3, 4, 5]), Ok(None));
+        assert_eq!(product.score(&[u64::MAX, 2]), Err(Overflow));
+        let sum = rule(Arity::AtLeast(2), Aggregation::Sum);
+        assert_eq!(sum.score(&[3, 4, 5]), Ok(Some(12)));
+        assert_eq!(sum.score(&[3]), Ok(None));
+        assert_eq!(sum.score(&[u64::MAX, 1]), Err(Overflow));
+        let max = rule(Arity::AtLeast(0), Aggregation::Max);
+        assert_eq!(max.score(&[3, 7, 5]), Ok(Some(7)));
+        assert_eq!(max.score(&[]), Ok(Some(0)));
+    }
+}
@@ -0,0 +1,330 @@
+use aoc_common::{error::parse_number, Grid, ParseError, Solution};
+
+use crate::{Day3, Rules};
+
+/// A run of digits on one row of the schematic, `length` cells wide from `(x, y)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Number {
+    pub x: usize,
+    pub y: usize,
+    pub length: usize,
+    pub value: u64,
+}
+
+impl Number {
+    /// The cells the number's digits occupy.
+    pub fn cells(&self) -> impl Iterator<Item = (usize, usize)> {
+        let y = self.y;
+        (self.x..self.x + self.length).map(move |x| (x, y))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Symbol {
+    pub x: usize,
+    pub y: usize,
+    pub c: char,
+}
+
+impl Symbol {
+    pub fn new((x, y): (usize, usize), c: char) -> Self {
+        Symbol { x, y, c }
+    }
+
+    pub fn position(&self) -> (usize, usize) {
+        (self.x, self.y)
+    }
+}
+
+/// A symbol whose rule accepts the numbers around it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gear<'a> {
+    pub symbol: &'a Symbol,
+    pub numbers: Vec<&'a Number>,
+    pub score: u64,
+}
+
+pub fn is_symbol(c: char) -> bool {
+    c.is_ascii_punctuation() && c != '.'
+}
+
+/// The engine schematic with its numbers and symbols, each in reading order. Positions
+/// are `(x, y)`, as for [`Grid`].
+#[derive(Debug, Clone)]
+pub struct Schematic {
+    grid: Grid<char>,
+    numbers: Vec<Number>,
+    symbols: Vec<Symbol>,
+    /// For every cell, the index into `numbers` of the number covering it.
+    index: Grid<Option<usize>>,
+}
+
+impl Schematic {
+    /// Reads the grid and collects its numbers and symbols, reporting numbers that don't
+    /// fit in `u64`.
+    pub fn parse(s: &str) -> Result<Self, ParseError> {
+        let grid = Grid::parse(Day3::DAY, s, Ok)?;
+        let mut numbers: Vec<Number> = vec![];
+        let mut symbols: Vec<Symbol> = vec![];
+        for (y, line) in s.lines().enumerate() {
+            let cells: Vec<(usize, char)> = line.char_indices().collect();
+            let mut x = 0;
+            while x < cells.len() {
+                let (idx, c) = cells[x];
+                if c.is_ascii_digit() {
+                    let length = cells[x..]
+                        .iter()
+                        .take_while(|(_, c)| c.is_ascii_digit())
+                        .count();
+                    let value = parse_number(Day3::DAY, s, &line[idx..idx + length])?;
+                    numbers.push(Number {
+                        x,
+                        y,
+                        length,
+                        value,
+                    });
+                    x += length;
+                    continue;
+                }
+                if is_symbol(c) {
+                    symbols.push(Symbol::new((x, y), c));
+                }
+                x += 1;
+            }
+        }
+        let mut index = grid.map(|_| None);
+        for (idx, number) in numbers.iter().enumerate() {
+            for pos in number.cells() {
+                index[pos] = Some(idx);
+            }
+        }
+        Ok(Schematic {
+            grid,
+            numbers,
+            symbols,
+            index,
+        })
+    }
+
+    pub fn grid(&self) -> &Grid<char> {
+        &self.grid
+    }
+
+    pub fn numbers(&self) -> &[Number] {
+        &self.numbers
+    }
+
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    /// The number with a digit at `pos`, if any.
+    pub fn number_at(&self, pos: (usize, usize)) -> Option<&Number> {
+        let idx = (*self.index.get(pos)?)?;
+        Some(&self.numbers[idx])
+    }
+
+    /// Indices into [`Schematic::numbers`] of the numbers around `pos`, each once, in
+    /// reading order. Only the eight surrounding cells are looked at.
+    fn adjacent_indices(&self, pos: (usize, usize)) -> Vec<usize> {
+        let mut indices: Vec<usize> = self
+            .index
+            .neighbors8(pos)
+            .filter_map(|pos| self.index[pos])
+            .collect();
+        indices.sort_unstable();
+        indices.dedup();
+        indices
+    }
+
+    /// The numbers around a symbol, each once, in reading order.
+    pub fn adjacent_numbers(&self, symbol: &Symbol) -> Vec<&Number> {
+        self.adjacent_indices(symbol.position())
+            .into_iter()
+            .map(|idx| &self.numbers[idx])
+            .collect()
+    }
+
+    /// Numbers next to at least one symbol, in reading order.
+    pub fn parts(&self) -> impl Iterator<Item = &Number> {
+        let mut is_part = vec![false; self.numbers.len()];
+        for symbol in self.symbols.iter() {
+            for idx in self.adjacent_indices(symbol.position()) {
+                is_part[idx] = true;
+            }
+        }
+        self.numbers
+            .iter()
+            .zip(is_part)
+            .filter_map(|(number, is_part)| is_part.then_some(number))
+    }
+
+    /// The sum of the part numbers, or the position of the part that takes it past
+    /// `u64`.
+    pub fn sum_of_parts(&self) -> Result<u64, (usize, usize)> {
+        self.parts().try_fold(0u64, |sum, part| {
+            sum.checked_add(part.value).ok_or((part.x, part.y))
+        })
+    }
+
+    /// Every symbol that scores under `rules`, in reading order, or the position of the
+    /// first whose score doesn't fit in `u64`.
+    pub fn gears(&self, rules: &Rules) -> Result<Vec<Gear<'_>>, (usize, usize)> {
+        let mut gears = vec![];
+        for symbol in self.symbols.iter() {
+            let Some(rule) = rules.get(symbol.c) else {
+                continue;
+            };
+            let numbers = self.adjacent_numbers(symbol);
+            let values: Vec<u64> = numbers.iter().map(|number| number.value).collect();
+            let score = rule.score(&values).map_err(|_| symbol.position())?;
+            if let Some(score) = score {
+                gears.push(Gear {
+                    symbol,
+                    numbers,
+                    score,
+                });
+            }
+        }
+        Ok(gears)
+    }
+
+    /// The sum of the gear scores under `rules`, or the position of the gear that takes
+    /// it past `u64`.
+    pub fn total_score(&self, rules: &Rules) -> Result<u64, (usize, usize)> {
+        self.gears(rules)?.iter().try_fold(0u64, |sum, gear| {
+            sum.checked_add(gear.score).ok_or(gear.symbol.position())
+        })
+    }
+
+    /// Scores the puzzle's gears: the product of the two numbers around every `*` that
+    /// touches exactly two.
+    pub fn sum_of_ratios(&self) -> Result<u64, (usize, usize)> {
+        self.total_score(&Rules::default())
+    }
+}
+
+#[cfg(test)]
+mod schematic_tests {
+    use std::str::FromStr;
+
+    use aoc_common::Solution;
+
+    use crate::{schematic::*, Day3};
+
+    fn schematic(s: &str) -> Schematic {
+        Day3::parse(s).unwrap()
+    }
+
+    #[test]
+    fn test_positions() {
+        let schematic = schematic("467..\n...*.\n..35#");
+        assert_eq!(
+            schematic.numbers(),
+            [
+                Number {
+                    x: 0,
+                    y: 0,
+                    length: 3,
+                    value: 467
+                },
+                Number {
+                    x: 2,
+                    y: 2,
+                    length: 2,
+                    value: 35
+                },
+            ]
+        );
+        let symbols: Vec<_> = schematic
+            .symbols()
+            .iter()
+            .map(|symbol| (symbol.position(), symbol.c))
+            .collect();
+        assert_eq!(symbols, vec![((3, 1), '*'), ((4, 2), '#')]);
+    }
+
+    #[test]
+    fn test_adjacency_box() {
+        assert_eq!(schematic("#...\n.12.\n....").sum_of_parts(), Ok(12));
+        assert_eq!(schematic("....\n.12#\n....").sum_of_parts(), Ok(12));
+        assert_eq!(schematic("....\n.12.\n...#").sum_of_parts(), Ok(12));
+        assert_eq!(schematic("#....\n..12.\n.....").sum_of_parts(), Ok(0));
+        assert_eq!(schematic(".....\n.12..\n....#").sum_of_parts(), Ok(0));
+        assert_eq!(schematic(".12.\n....\n#...").sum_of_parts(), Ok(0));
+    }
+
+    #[test]
+    fn test_no_wrap_at_row_end() {
+        // The number ends one row and the symbol starts the next.
+        assert_eq!(schematic("...12\n#....").sum_of_parts(), Ok(0));
+        assert_eq!(schematic("....#\n12...").sum_of_parts(), Ok(0));
+        assert_eq!(schematic("...12\n....#").sum_of_parts(), Ok(12));
+        assert_eq!(schematic("#....\n12...").sum_of_parts(), Ok(12));
+    }
+
+    #[test]
+    fn test_edges() {
+        assert_eq!(schematic("12#").sum_of_parts(), Ok(12));
+        assert_eq!(schematic("#12").sum_of_parts(), Ok(12));
+        assert_eq!(schematic("5..\n..*\n..7").sum_of_parts(), Ok(7));
+        assert_eq!(schematic("..5\n*..\n..7").sum_of_ratios(), Ok(0));
+        assert_eq!(schematic("5..\n*..\n7..").sum_of_ratios(), Ok(35));
+    }
+
+    #[test]
+    fn test_index() {
+        let schematic = schematic("467..\n...*.\n..35#");
+        assert_eq!(schematic.number_at((2, 0)).map(|n| n.value), Some(467));
+        assert_eq!(schematic.number_at((3, 0)), None);
+        assert_eq!(schematic.number_at((0, 5)), None);
+        let values: Vec<u64> = schematic
+            .adjacent_numbers(&schematic.symbols()[0])
+            .iter()
+            .map(|number| number.value)
+            .collect();
+        assert_eq!(values, vec![467, 35]);
+        assert_eq!(schematic.parts().count(), 2);
+    }
+
+    #[test]
+    fn test_gear_rules() {
+        let schematic = schematic("1.2.3\n.#*$.\n4.5..");
+        let rules = Rules::from_str("* 2 sum\n# 4+ product\n$ 2 max").unwrap();
+        let gears: Vec<(char, u64)> = schematic
+            .gears(&rules)
+            .unwrap()
+            .iter()
+            .map(|gear| (gear.symbol.c, gear.score))
+            .collect();
+        assert_eq!(gears, vec![('#', 40), ('*', 7)]);
+        let rules = Rules::from_str("# 3 product\n$ 2+ max").unwrap();
+        assert_eq!(schematic.total_score(&rules), Ok(5));
+        assert_eq!(schematic.sum_of_ratios(), Ok(10));
+    }
+
+    #[test]
+    fn test_large_schematic() {
+        // The example's edge columns and rows hold no symbols, so tiles don't interact.
+        let example: Vec<&str> = include_str!("../example").lines().collect();
+        let tiles = 100;
+        let rows: Vec<String> = (0..tiles)
+            .flat_map(|_| example.iter().map(|line| line.repeat(tiles)))
+            .collect();
+        let schematic = schematic(&rows.join("\n"));
+        let count = (tiles * tiles) as u64;
+        assert_eq!(schematic.sum_of_parts(), Ok(4361 * count));
+        assert_eq!(schematic.sum_of_ratios(), Ok(467835 * count));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let max = u64::MAX;
+        assert_eq!(schematic(&format!("{max}#")).sum_of_parts(), Ok(max));
+        assert_eq!(schematic(&format!("{max}#1")).sum_of_parts(), Err((21, 0)));
+        assert_eq!(
+            schematic("4294967296*4294967296").sum_of_ratios(),
+            Err((10, 0))
+        );
+    }
+}
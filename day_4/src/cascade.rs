@@ -0,0 +1,159 @@
+use std::fmt::Write;
+
+use crate::{Card, Deck};
+
+/// How one card of the deck ends up with its copies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step<'a> {
+    pub card: &'a Card,
+    /// Copies held once every win is paid out, including the original.
+    pub copies: u64,
+    /// The own numbers that are winning numbers, in card order.
+    pub winning_numbers: Vec<u32>,
+    /// Each earlier card that won this one, with the copies it handed over.
+    pub sources: Vec<(u32, u64)>,
+}
+
+impl Step<'_> {
+    pub fn hits(&self) -> u32 {
+        self.card.get_num_of_hits()
+    }
+}
+
+/// Walks the cascade of [`Deck::count_cards`], also recording where each card's copies
+/// came from. Fails with the id of the card whose copies overflow `u64`.
+pub fn cascade(deck: &Deck) -> Result<Vec<Step<'_>>, u32> {
+    let cards = deck.cards();
+    let mut sources = vec![vec![]; cards.len()];
+    let copies = deck.pay_out(|from, to, count| sources[to].push((cards[from].id, count)))?;
+    let steps = cards
+        .iter()
+        .zip(copies)
+        .zip(sources)
+        .map(|((card, copies), sources)| Step {
+            card,
+            copies,
+            winning_numbers: card.winning_numbers().collect(),
+            sources,
+        })
+        .collect();
+    Ok(steps)
+}
+
+/// The total number of cards the cascade ends with, or the id of the card that takes it
+/// past `u64`.
+pub fn total(steps: &[Step<'_>]) -> Result<u64, u32> {
+    steps.iter().try_fold(0u64, |total, step| {
+        total.checked_add(step.copies).ok_or(step.card.id)
+    })
+}
+
+/// One row per card: its copies, hits, winning numbers and the cards it got copies from.
+pub fn table(steps: &[Step<'_>]) -> String {
+    let mut out = format!(
+        "{:>6}  {:>8}  {:>4}  {:<20}  {}\n",
+        "Card", "Copies", "Hits", "Winning numbers", "From"
+    );
+    for step in steps.iter() {
+        let numbers: Vec<String> = step.winning_numbers.iter().map(u32::to_string).collect();
+        let sources: Vec<String> = step
+            .sources
+            .iter()
+            .map(|(id, count)| format!("{id} (x{count})"))
+            .collect();
+        let numbers = if numbers.is_empty() {
+            String::from("-")
+        } else {
+            numbers.join(" ")
+        };
+        let sources = if sources.is_empty() {
+            String::from("-")
+        } else {
+            sources.join(", ")
+        };
+        let _ = writeln!(
+            out,
+            "{:>6}  {:>8}  {:>4}  {:<20}  {}",
+            step.card.id,
+            step.copies,
+            step.hits(),
+            numbers,
+            sources
+        );
+    }
+    out
+}
+
+/// The cascade as a Graphviz digraph: a node per card labelled with its copies and an
+/// edge from each winning card to each card it won, labelled with the copies handed over.
+pub fn dot(steps: &[Step<'_>]) -> String {
+    let mut out = String::from("digraph cascade {\n    rankdir=LR;\n    node [shape=box];\n");
+    for step in steps.iter() {
+        let _ = writeln!(
+            out,
+            "    card_{} [label=\"Card {}\\n{} {}\"];",
+            step.card.id,
+            step.card.id,
+            step.copies,
+            if step.copies == 1 { "copy" } else { "copies" }
+        );
+    }
+    for step in steps.iter() {
+        for (id, count) in step.sources.iter() {
+            let _ = writeln!(
+                out,
+                "    card_{id} -> card_{} [label=\"{count}\"];",
+                step.card.id
+            );
+        }
+    }
+    out.push_str("}\n");
+    out
+}
+
+#[cfg(test)]
+mod cascade_tests {
+    use std::str::FromStr;
+
+    use crate::cascade::*;
+
+    fn deck() -> Deck {
+        Deck::from_str(include_str!("../example")).unwrap()
+    }
+
+    #[test]
+    fn test_cascade() {
+        let deck = deck();
+        let steps = cascade(&deck).unwrap();
+        let copies: Vec<u64> = steps.iter().map(|step| step.copies).collect();
+        assert_eq!(copies, vec![1, 2, 4, 8, 14, 1]);
+        assert_eq!(total(&steps), deck.count_cards());
+        assert_eq!(steps[0].winning_numbers, vec![83, 86, 17, 48]);
+        assert_eq!(steps[4].sources, vec![(1, 1), (3, 4), (4, 8)]);
+        assert!(steps[5].sources.is_empty());
+    }
+
+    #[test]
+    fn test_table() {
+        let deck = deck();
+        let table = table(&cascade(&deck).unwrap());
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 7);
+        assert_eq!(
+            lines[3],
+            "     3         4     2  21 1                  1 (x1), 2 (x2)"
+        );
+        assert!(lines[6].ends_with("0  -                     -"));
+    }
+
+    #[test]
+    fn test_dot() {
+        let deck = deck();
+        let dot = dot(&cascade(&deck).unwrap());
+        assert!(dot.starts_with("digraph cascade {\n"));
+        assert!(dot.contains("    card_1 [label=\"Card 1\\n1 copy\"];\n"));
+        assert!(dot.contains("    card_4 -> card_5 [label=\"8\"];\n"));
+        assert_eq!(dot.matches("->").count(), 4 + 2 + 2 + 1);
+        assert!(dot.ends_with("}\n"));
+    }
+}
@@ -0,0 +1,283 @@
+pub mod cascade;
+
+use std::{collections::HashSet, str::FromStr};
+
+use aoc_common::{Answer, ParseError, Solution};
+
+const PREFIX: &str = "Card";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Card {
+    pub id: u32,
+    pub own: Vec<u32>,
+    pub winning: HashSet<u32>,
+}
+
+/// `Card <id>: <winning numbers> | <own numbers>`, with any amount of whitespace between
+/// the fields and numbers.
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (head, numbers) = s.split_once(':').ok_or_else(|| {
+            ParseError::new(Day4::DAY, "expected `Card <id>: <winning> | <own>`").at(s, s.trim())
+        })?;
+        let id = head
+            .trim()
+            .strip_prefix(PREFIX)
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .ok_or_else(|| ParseError::new(Day4::DAY, "expected `Card <id>`").at(s, head.trim()))?;
+        let id: u32 = id
+            .parse()
+            .map_err(|_| ParseError::new(Day4::DAY, "invalid card id").at(s, id))?;
+        let (winning, own) = numbers.split_once('|').ok_or_else(|| {
+            ParseError::new(
+                Day4::DAY,
+                format!("card {id}: expected `|` between winning and own numbers"),
+            )
+            .at(s, numbers.trim())
+        })?;
+        Ok(Card {
+            id,
+            own: parse_numbers(s, id, "own", own)?,
+            winning: parse_numbers(s, id, "winning", winning)?,
+        })
+    }
+}
+
+/// Parses the whitespace-separated numbers of one field of card `id`.
+fn parse_numbers<T: FromIterator<u32>>(
+    source: &str,
+    id: u32,
+    field: &str,
+    numbers: &str,
+) -> Result<T, ParseError> {
+    numbers
+        .split_whitespace()
+        .map(|n| {
+            n.parse().map_err(|_| {
+                ParseError::new(Day4::DAY, format!("card {id}: invalid {field} number"))
+                    .at(source, n)
+            })
+        })
+        .collect()
+}
+
+impl Card {
+    /// The card's points, doubling with every hit after the first, or `None` when they
+    /// don't fit in `u64`.
+    pub fn get_points(&self) -> Option<u64> {
+        let hits = self.get_num_of_hits();
+        match hits {
+            0 => Some(0),
+            _ => 1u64.checked_shl(hits - 1),
+        }
+    }
+
+    /// The own numbers that are winning numbers, in card order.
+    pub fn winning_numbers(&self) -> impl Iterator<Item = u32> + '_ {
+        self.own
+            .iter()
+            .copied()
+            .filter(|own_num| self.winning.contains(own_num))
+    }
+
+    pub fn get_num_of_hits(&self) -> u32 {
+        self.winning_numbers().count() as u32
+    }
+}
+
+/// Every scratchcard of the table, in order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Deck {
+    cards: Vec<Card>,
+}
+
+impl FromStr for Deck {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cards = s
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| Card::from_str(line).map_err(|err| err.within(s, line)))
+            .collect::<Result<Vec<Card>, ParseError>>()?;
+        Ok(Deck { cards })
+    }
+}
+
+impl Deck {
+    pub fn new(cards: Vec<Card>) -> Self {
+        Deck { cards }
+    }
+
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// The points of every card added up, or the id of the card that takes them past
+    /// `u64`.
+    pub fn total_points(&self) -> Result<u64, u32> {
+        self.cards.iter().try_fold(0u64, |total, card| {
+            card.get_points()
+                .and_then(|points| total.checked_add(points))
+                .ok_or(card.id)
+        })
+    }
+
+    /// The total number of cards held once every win is paid out, or the id of the card
+    /// whose copies overflow `u64`.
+    pub fn count_cards(&self) -> Result<u64, u32> {
+        let copies = self.pay_out(|_, _, _| {})?;
+        self.cards
+            .iter()
+            .zip(copies)
+            .try_fold(0u64, |total, (card, count)| {
+                total.checked_add(count).ok_or(card.id)
+            })
+    }
+
+    /// The copies of each card held once every win is paid out, including the original,
+    /// or the id of the card whose copies overflow `u64`. `on_win(from, to, count)` sees
+    /// every hand-over of `count` copies from the card at index `from` to the one at `to`.
+    ///
+    /// Copies only ever go to later cards, so one pass in table order settles each card's
+    /// count before it is handed on: every copy of a card with `n` hits adds one copy of
+    /// each of the next `n` cards. Wins past the end of the table are dropped.
+    pub(crate) fn pay_out(
+        &self,
+        mut on_win: impl FnMut(usize, usize, u64),
+    ) -> Result<Vec<u64>, u32> {
+        let cards = &self.cards;
+        let mut copies = vec![1u64; cards.len()];
+        for (idx, card) in cards.iter().enumerate() {
+            let count = copies[idx];
+            let won = (idx + 1)..(idx + 1 + card.get_num_of_hits() as usize).min(cards.len());
+            for later in won {
+                copies[later] = copies[later].checked_add(count).ok_or(cards[later].id)?;
+                on_win(idx, later, count);
+            }
+        }
+        Ok(copies)
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Deck;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Deck::from_str(input)
+    }
+
+    fn part_one(deck: &Self::Input) -> Answer {
+        total_points(deck).into()
+    }
+
+    fn part_two(deck: &Self::Input) -> Answer {
+        count_cards(deck).into()
+    }
+}
+
+fn total_points(deck: &Deck) -> u64 {
+    deck.total_points()
+        .unwrap_or_else(|id| panic!("Points overflow u64 at card {id}"))
+}
+
+fn count_cards(deck: &Deck) -> u64 {
+    deck.count_cards()
+        .unwrap_or_else(|id| panic!("Card count overflows u64 at card {id}"))
+}
+
+pub fn part_one(file: &str) -> u64 {
+    let deck = Deck::from_str(file).unwrap_or_else(|err| panic!("{err}"));
+    total_points(&deck)
+}
+
+pub fn part_two(file: &str) -> u64 {
+    let deck = Deck::from_str(file).unwrap_or_else(|err| panic!("{err}"));
+    count_cards(&deck)
+}
+
+#[cfg(test)]
+mod day_4_tests {
+    use crate::*;
+
+    #[test]
+    fn test_part_1_example() {
+        assert_eq!(part_one(include_str!("../example")), 13);
+    }
+
+    #[test]
+    fn test_part_2_example() {
+        assert_eq!(part_two(include_str!("../example")), 30);
+    }
+
+    /// `len` cards where each one wins the next `hits` cards.
+    fn cascade(len: u32, hits: u32) -> Deck {
+        Deck::new(
+            (1..=len)
+                .map(|id| Card {
+                    id,
+                    own: (1..=hits).collect(),
+                    winning: (1..=hits).collect(),
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_long_cascade() {
+        // Card i ends up with i copies, which the old copy-by-copy loop walked one by one.
+        let deck = cascade(5000, 1);
+        assert_eq!(deck.count_cards(), Ok(5000 * 5001 / 2));
+        assert_eq!(cascade(3, 10).count_cards(), Ok(1 + 2 + 4));
+    }
+
+    #[test]
+    fn test_cascade_overflow() {
+        // Every card wins all later ones, so card i has 2^(i - 1) copies.
+        assert_eq!(cascade(64, 64).count_cards(), Ok(u64::MAX));
+        assert_eq!(cascade(65, 65).count_cards(), Err(65));
+    }
+
+    #[test]
+    fn test_points_overflow() {
+        // A card with 64 hits is worth 2^63 points, and a second one overflows the sum.
+        assert_eq!(cascade(1, 64).total_points(), Ok(1 << 63));
+        assert_eq!(cascade(2, 64).total_points(), Err(2));
+        assert_eq!(cascade(1, 65).total_points(), Err(1));
+    }
+
+    #[test]
+    fn test_parse_card() {
+        let card = Card::from_str("Card   12:  41 48 |  83 86  6  ").unwrap();
+        assert_eq!(card.id, 12);
+        assert_eq!(card.winning, HashSet::from([41, 48]));
+        assert_eq!(card.own, vec![83, 86, 6]);
+        assert_eq!(card.get_num_of_hits(), 0);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Deck::from_str("Card 1: 1 2 | 3\nCard 2: 4 5 6").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 9));
+        assert_eq!(
+            err.reason,
+            "card 2: expected `|` between winning and own numbers"
+        );
+        let err = Deck::from_str("Card 1: 1 2 | 3\nCard 2: 4 | 5 x").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 15, "x"));
+        assert_eq!(err.reason, "card 2: invalid own number");
+        let err = Card::from_str("Card 7: 1 -2 | 3").unwrap_err();
+        assert_eq!(err.reason, "card 7: invalid winning number");
+        let err = Card::from_str("Card x: 1 | 3").unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (6, "x"));
+        assert!(Card::from_str("Game 1: 1 | 3").is_err());
+        assert!(Card::from_str("Card 1 1 | 3").is_err());
+    }
+}
@@ -1,162 +1,42 @@
-use std::collections::BTreeMap;
+use std::str::FromStr;
 
-fn main() {
-    let file = include_str!("../input").to_owned();
-    println!("Part 1: {}", part_one(String::from(&file)));
-    println!("Part 2: {}", part_two(file));
-}
-
-#[derive(Debug)]
-struct Card {
-    id: u32,
-    own: Vec<u32>,
-    winning: Vec<u32>,
-}
-
-impl Card {
-    fn get_points(&self) -> u32 {
-        let hits = self.get_num_of_hits();
-        match hits {
-            0 => 0,
-            _ => 1 * (2 as u32).pow(hits - 1),
-        }
-    }
-
-    fn get_num_of_hits(&self) -> u32 {
-        let mut hits = 0;
-        for own_num in self.own.iter() {
-            if self.winning.contains(own_num) {
-                hits += 1;
-            }
-        }
-        hits
-    }
-}
-
-pub fn part_one(file: String) -> u32 {
-    let cards: Vec<Card> = file
-        .lines()
-        .map(|line| {
-            let id = line
-                .split(":")
-                .next()
-                .unwrap()
-                .strip_prefix("Card ")
-                .unwrap()
-                .trim()
-                .parse::<u32>()
-                .expect("Failed to parse u32");
-            let winning = line
-                .split(":")
-                .last()
-                .unwrap()
-                .split("|")
-                .next()
-                .unwrap()
-                .trim()
-                .split_whitespace()
-                .map(|n| n.trim().parse::<u32>().expect("Failed to parse u32"))
-                .collect();
-            let own = line
-                .split(":")
-                .last()
-                .unwrap()
-                .split("|")
-                .last()
-                .unwrap()
-                .trim()
-                .split_whitespace()
-                .map(|n| n.trim().parse::<u32>().expect("Failed to parse u32"))
-                .collect();
-
-            Card { id, own, winning }
-        })
-        .collect();
-    cards.iter().map(|c| c.get_points()).sum::<u32>()
-}
+use aoc_common::{
+    cli::{self, Args},
+    input::InputSource,
+    Solution,
+};
+use day_4::{cascade, Day4, Deck};
 
-pub fn part_two(file: String) -> u32 {
-    let mut map: BTreeMap<u32, u32> = BTreeMap::new();
-    let cards: Vec<Card> = file
-        .lines()
-        .map(|line| {
-            let id = line
-                .split(":")
-                .next()
-                .unwrap()
-                .strip_prefix("Card ")
-                .unwrap()
-                .trim()
-                .parse::<u32>()
-                .expect("Failed to parse u32");
-            let winning = line
-                .split(":")
-                .last()
-                .unwrap()
-                .split("|")
-                .next()
-                .unwrap()
-                .trim()
-                .split_whitespace()
-                .map(|n| n.trim().parse::<u32>().expect("Failed to parse u32"))
-                .collect();
-            let own = line
-                .split(":")
-                .last()
-                .unwrap()
-                .split("|")
-                .last()
-                .unwrap()
-                .trim()
-                .split_whitespace()
-                .map(|n| n.trim().parse::<u32>().expect("Failed to parse u32"))
-                .collect();
-
-            Card { id, own, winning }
-        })
-        .collect();
-
-    for card in cards.iter() {
-        if let Some(v) = map.get(&card.id) {
-            map.insert(card.id, v + 1);
-        } else {
-            map.insert(card.id, 1);
-        }
-
-        let t = map.get(&card.id).unwrap();
-        for _ in 0..*t {
-            handle_card(card, &mut map);
-        }
-    }
-    map.values().sum()
+fn main() {
+    cli::exit_on_error(run(Args::from_env()));
 }
 
-fn handle_card(card: &Card, map: &mut BTreeMap<u32, u32>) {
-    let hits = card.get_num_of_hits();
-    if hits == 0 {
-        return;
+/// Solves both parts, or with `--explain` prints how part two's copies cascade from card
+/// to card. `--dot` prints the same cascade as a Graphviz graph.
+fn run(mut args: Args) -> Result<(), String> {
+    let explain = args.flag("--explain");
+    let dot = args.flag("--dot");
+    if !explain && !dot {
+        return cli::try_run::<Day4>(args);
     }
-
-    for id in card.id + 1..=card.id + hits {
-        if let Some(v) = map.get(&id) {
-            map.insert(id, v + 1);
-        } else {
-            map.insert(id, 1);
-        }
+    if explain && dot {
+        return Err(String::from("--explain and --dot can't be used together"));
     }
-}
-
-#[cfg(test)]
-mod day_4_tests {
-    use crate::*;
-
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_one(include_str!("../example").to_owned()), 13);
-    }
-
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(part_two(include_str!("../example").to_owned()), 30);
+    let source = InputSource::from_arg(args.value("--input")?.as_deref(), Day4::DAY);
+    args.finish()?;
+
+    let input = source.read().map_err(|err| err.to_string())?;
+    let deck =
+        Deck::from_str(&input).map_err(|err| format!("Failed to parse `{source}`: {err}"))?;
+    let steps =
+        cascade::cascade(&deck).map_err(|id| format!("Card count overflows u64 at card {id}"))?;
+    if dot {
+        print!("{}", cascade::dot(&steps));
+    } else {
+        print!("{}", cascade::table(&steps));
+        let total = cascade::total(&steps)
+            .map_err(|id| format!("Card count overflows u64 at card {id}"))?;
+        println!("Total cards: {total}");
     }
+    Ok(())
 }
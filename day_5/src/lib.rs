@@ -0,0 +1,211 @@
+use rayon::prelude::*;
+use std::{ops::Range, str::FromStr};
+
+use aoc_common::{error::parse_number, Answer, ParseError, Solution};
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct RangeConverter {
+    pub source_range: Range<usize>,
+    pub destination_range: Range<usize>,
+}
+impl FromStr for RangeConverter {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens: Vec<&str> = s.split_ascii_whitespace().collect();
+        let [dest_range_start, src_range_start, range_size] = tokens[..] else {
+            return Err(ParseError::new(
+                Day5::DAY,
+                "expected destination start, source start and range length",
+            )
+            .at(s, s.trim()));
+        };
+        let [dest_range_start, src_range_start, range_size]: [usize; 3] = [
+            parse_number(Day5::DAY, s, dest_range_start)?,
+            parse_number(Day5::DAY, s, src_range_start)?,
+            parse_number(Day5::DAY, s, range_size)?,
+        ];
+        let range = |start: usize| {
+            start
+                .checked_add(range_size)
+                .map(|end| start..end)
+                .ok_or_else(|| ParseError::new(Day5::DAY, "range end overflows").at(s, s.trim()))
+        };
+        let result = RangeConverter {
+            source_range: range(src_range_start)?,
+            destination_range: range(dest_range_start)?,
+        };
+        Ok(result)
+    }
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct CategoryConverter {
+    pub name: String,
+    pub range_converters: Vec<RangeConverter>,
+}
+
+impl FromStr for CategoryConverter {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.trim().lines();
+        let header = lines.next().unwrap_or_default();
+        let name = header.strip_suffix(" map:").ok_or_else(|| {
+            ParseError::new(Day5::DAY, "expected a `<category> map:` header").at(s, header)
+        })?;
+        let mut range_converters = vec![];
+        for line in lines {
+            range_converters
+                .push(RangeConverter::from_str(line).map_err(|err| err.within(s, line))?);
+        }
+
+        let result = CategoryConverter {
+            name: String::from(name),
+            range_converters,
+        };
+        Ok(result)
+    }
+}
+
+impl CategoryConverter {
+    pub fn convert(&self, number: usize) -> usize {
+        self.range_converters
+            .iter()
+            .find_map(|converter| {
+                if converter.source_range.contains(&number) {
+                    Some(
+                        converter.destination_range.start + (number - converter.source_range.start),
+                    )
+                } else {
+                    None
+                }
+            })
+            .unwrap_or(number)
+    }
+}
+
+#[derive(Default, Debug, PartialEq, Clone)]
+pub struct Almanac {
+    pub seeds: Vec<usize>,
+    pub category_converters: Vec<CategoryConverter>,
+}
+
+impl FromStr for Almanac {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut categories = s.trim().split("\n\n");
+        let header = categories.next().unwrap_or_default();
+        let seeds = header
+            .strip_prefix("seeds: ")
+            .ok_or_else(|| ParseError::new(Day5::DAY, "expected `seeds: `").at(s, header))?
+            .split_ascii_whitespace()
+            .map(|seed| parse_number(Day5::DAY, s, seed))
+            .collect::<Result<Vec<usize>, ParseError>>()?;
+        let category_converters = categories
+            .map(|category| {
+                CategoryConverter::from_str(category).map_err(|err| err.within(s, category))
+            })
+            .collect::<Result<Vec<CategoryConverter>, ParseError>>()?;
+        let res = Almanac {
+            seeds,
+            category_converters,
+        };
+        Ok(res)
+    }
+}
+
+impl Almanac {
+    pub fn min_location(&self) -> usize {
+        self.seeds
+            .par_iter()
+            .map(|&seed| {
+                self.category_converters
+                    .iter()
+                    .fold(seed, |acc, converter| converter.convert(acc))
+            })
+            .min()
+            .unwrap_or_default()
+    }
+
+    pub fn set_seeds_as_ranges(&mut self) {
+        self.seeds = self
+            .seeds
+            .par_chunks(2)
+            .flat_map(|chunk| {
+                if let [start, size] = chunk {
+                    let start = *start;
+                    let size = *size;
+                    let range = start..(start + size);
+                    range.into_iter()
+                } else {
+                    let empty = 0..0;
+                    empty.into_iter()
+                }
+            })
+            .collect();
+    }
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Almanac::from_str(input)
+    }
+
+    fn part_one(almanac: &Self::Input) -> Answer {
+        almanac.min_location().into()
+    }
+
+    fn part_two(almanac: &Self::Input) -> Answer {
+        let mut almanac = almanac.clone();
+        almanac.set_seeds_as_ranges();
+        almanac.min_location().into()
+    }
+}
+
+pub fn part_one(file: &str) -> usize {
+    let almanac = Almanac::from_str(file).unwrap_or_else(|err| panic!("{err}"));
+    almanac.min_location()
+}
+
+pub fn part_two(file: &str) -> usize {
+    let mut almanac = Almanac::from_str(file).unwrap_or_else(|err| panic!("{err}"));
+    almanac.set_seeds_as_ranges();
+    almanac.min_location()
+}
+
+#[cfg(test)]
+mod day_5_tests {
+    use crate::*;
+
+    #[test]
+    fn test_part_1_example() {
+        assert_eq!(part_one(include_str!("../example")), 35);
+    }
+
+    #[test]
+    fn test_part_2_example() {
+        assert_eq!(part_two(include_str!("../example")), 46);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 5O 48";
+        let err = Almanac::from_str(input).unwrap_err();
+        assert_eq!((err.line, err.column), (5, 4));
+        assert_eq!(err.text, "5O");
+        let input = format!("seeds: 79\n\nseed-to-soil map:\n50 {} 2", usize::MAX);
+        let err = Almanac::from_str(&input).unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.reason.as_str()),
+            (4, 1, "range end overflows")
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use std::str::FromStr;
+
+use aoc_common::{error::parse_number, Answer, ParseError, Solution};
+
+#[derive(Debug)]
+pub struct Race {
+    time_limit: usize,
+    record: usize,
+}
+
+impl Race {
+    pub fn num_ways_to_beat(&self) -> usize {
+        let mut total = 0;
+        for held in 0..=self.time_limit {
+            let distance = held * (self.time_limit - held);
+            if distance > self.record {
+                total += 1;
+            }
+        }
+        total
+    }
+}
+
+/// The race sheet, read both as separate races and as one race with the spaces removed.
+#[derive(Debug)]
+pub struct Sheet {
+    races: Vec<Race>,
+    kerned: Race,
+}
+
+impl FromStr for Sheet {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+        let times = labelled(s, lines.next(), "Time:")?;
+        let records = labelled(s, lines.next(), "Distance:")?;
+        let numbers = |row: &str| {
+            row.split_whitespace()
+                .map(|token| parse_number(Day6::DAY, s, token))
+                .collect::<Result<Vec<usize>, ParseError>>()
+        };
+        let (time_limits, distances) = (numbers(times)?, numbers(records)?);
+        if time_limits.len() != distances.len() {
+            return Err(
+                ParseError::new(Day6::DAY, "expected a distance for every time").at(s, records),
+            );
+        }
+        let races = time_limits
+            .into_iter()
+            .zip(distances)
+            .map(|(time_limit, record)| Race { time_limit, record })
+            .collect();
+        let kerned = Race {
+            time_limit: kern(s, times)?,
+            record: kern(s, records)?,
+        };
+        Ok(Sheet { races, kerned })
+    }
+}
+
+/// The numbers following `label` on `line`.
+fn labelled<'a>(s: &'a str, line: Option<&'a str>, label: &str) -> Result<&'a str, ParseError> {
+    let line = line.unwrap_or_default();
+    line.strip_prefix(label).ok_or_else(|| {
+        ParseError::new(
+            Day6::DAY,
+            format!("expected a line starting with `{label}`"),
+        )
+        .at(s, line)
+    })
+}
+
+/// Reads every digit of a row as a single number.
+fn kern(s: &str, row: &str) -> Result<usize, ParseError> {
+    let digits: String = row.split_whitespace().collect();
+    digits
+        .parse()
+        .map_err(|_| ParseError::new(Day6::DAY, "invalid kerned number").at(s, row.trim()))
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = Sheet;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Sheet::from_str(input)
+    }
+
+    fn part_one(sheet: &Self::Input) -> Answer {
+        sheet
+            .races
+            .iter()
+            .map(|r| r.num_ways_to_beat())
+            .product::<usize>()
+            .into()
+    }
+
+    fn part_two(sheet: &Self::Input) -> Answer {
+        sheet.kerned.num_ways_to_beat().into()
+    }
+}
+
+pub fn part_one(file: &str) -> usize {
+    let sheet = Sheet::from_str(file).unwrap_or_else(|err| panic!("{err}"));
+    sheet.races.iter().map(|r| r.num_ways_to_beat()).product()
+}
+
+pub fn part_two(file: &str) -> usize {
+    let sheet = Sheet::from_str(file).unwrap_or_else(|err| panic!("{err}"));
+    sheet.kerned.num_ways_to_beat()
+}
+
+#[cfg(test)]
+mod day_6_tests {
+    use crate::*;
+
+    #[test]
+    fn test_part_1_example() {
+        assert_eq!(part_one(include_str!("../example")), 288);
+    }
+
+    #[test]
+    fn test_part_2_example() {
+        assert_eq!(part_two(include_str!("../example")), 71503);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Sheet::from_str("Time: 7 1x\nDistance: 9 40").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 9, "1x"));
+        let err = Sheet::from_str("Time: 7 15\nRecord: 9 40").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert!(Sheet::from_str("Time: 7 15\nDistance: 9").is_err());
+        assert!(Sheet::from_str("Time: 99999999999 99999999999\nDistance: 1 1").is_err());
+    }
+}
@@ -0,0 +1,320 @@
+use std::{cmp::Ordering, collections::HashMap, str::FromStr};
+
+use aoc_common::{error::parse_number, Answer, ParseError, Solution};
+
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct Card {
+    pub label: char,
+    pub joker: bool,
+}
+
+impl Card {
+    const LABELS: [char; 13] = [
+        'A', 'K', 'Q', 'J', 'T', '9', '8', '7', '6', '5', '4', '3', '2',
+    ];
+    const JOKER_LABELS: [char; 13] = [
+        'A', 'K', 'Q', 'T', '9', '8', '7', '6', '5', '4', '3', '2', 'J',
+    ];
+
+    pub fn strength(&self) -> usize {
+        let labels = if self.joker {
+            Card::JOKER_LABELS
+        } else {
+            Card::LABELS
+        };
+        labels.len()
+            - labels
+                .iter()
+                .position(|&curr| curr == self.label)
+                .unwrap_or_default()
+    }
+}
+
+impl Ord for Card {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.strength().cmp(&other.strength())
+    }
+}
+
+impl PartialOrd for Card {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<char> for Card {
+    fn from(value: char) -> Self {
+        Card {
+            label: value,
+            joker: false,
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum HandType {
+    #[default]
+    Five = 7,
+    Four = 6,
+    FullHouse = 5,
+    Three = 4,
+    TwoPair = 3,
+    OnePair = 2,
+    HighCard = 1,
+}
+
+impl HandType {
+    pub const fn strength(&self) -> usize {
+        *self as usize
+    }
+}
+
+impl Ord for HandType {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.strength().cmp(&other.strength())
+    }
+}
+
+impl PartialOrd for HandType {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct Hand {
+    cards: [Card; 5],
+    bid: usize,
+    jokers: bool,
+}
+
+impl FromStr for Hand {
+    type Err = ParseError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut input = string.split_ascii_whitespace();
+        let (Some(labels), Some(bid), None) = (input.next(), input.next(), input.next()) else {
+            return Err(
+                ParseError::new(Day7::DAY, "expected cards followed by a bid")
+                    .at(string, string.trim()),
+            );
+        };
+        if let Some((idx, label)) = labels
+            .char_indices()
+            .find(|(_, label)| !Card::LABELS.contains(label))
+        {
+            return Err(ParseError::new(Day7::DAY, "unknown card label")
+                .at(string, &labels[idx..idx + label.len_utf8()]));
+        }
+        let cards = array_init::from_iter(labels.chars().map(Card::from))
+            .filter(|_| labels.len() == 5)
+            .ok_or_else(|| ParseError::new(Day7::DAY, "expected five cards").at(string, labels))?;
+        let result = Hand {
+            cards,
+            bid: parse_number(Day7::DAY, string, bid)?,
+            jokers: false,
+        };
+        Ok(result)
+    }
+}
+
+impl Hand {
+    pub fn with_jokers(mut self) -> Self {
+        self.jokers = true;
+        for card in self.cards.iter_mut() {
+            card.joker = true;
+        }
+        self
+    }
+
+    pub fn hand_type(&self) -> HandType {
+        let mut counts: HashMap<char, usize> = HashMap::new();
+
+        for card in &self.cards {
+            if let Some(label_count) = counts.get_mut(&card.label) {
+                *label_count += 1;
+            } else {
+                counts.insert(card.label, 1);
+            }
+        }
+        if !self.jokers {
+            let mut sizes: Vec<usize> = counts.values().copied().collect();
+            sizes.sort_unstable_by(|a, b| b.cmp(a));
+            return match sizes[..] {
+                [5] => HandType::Five,
+                [4, _] => HandType::Four,
+                [3, 2] => HandType::FullHouse,
+                [3, ..] => HandType::Three,
+                [2, 2, _] => HandType::TwoPair,
+                [2, ..] => HandType::OnePair,
+                _ => HandType::HighCard,
+            };
+        }
+
+        let keys = counts.keys();
+
+        if keys.len() == 1 {
+            return HandType::Five;
+        }
+        if keys.len() == 2 && counts.iter().any(|(_, &val)| val == 4) {
+            if counts.contains_key(&'J') {
+                return HandType::Five;
+            }
+            return HandType::Four;
+        }
+        if keys.len() == 2 && counts.iter().any(|(_, &val)| val == 3) {
+            if counts.contains_key(&'J') {
+                return HandType::Five;
+            }
+            return HandType::FullHouse;
+        }
+        if keys.len() == 3 && counts.iter().any(|(_, &val)| val == 3) {
+            if counts.contains_key(&'J') {
+                return HandType::Four;
+            }
+            return HandType::Three;
+        }
+        if keys.len() == 3 && counts.iter().any(|(_, &val)| val == 2) {
+            if let Some(val) = counts.get(&'J') {
+                if *val == 2 {
+                    return HandType::Four;
+                } else if *val == 1 {
+                    return HandType::FullHouse;
+                }
+            }
+            return HandType::TwoPair;
+        }
+        if keys.len() == 4 {
+            if counts.contains_key(&'J') {
+                return HandType::Three;
+            }
+            return HandType::OnePair;
+        }
+        if keys.len() == 5 {
+            if counts.contains_key(&'J') {
+                return HandType::OnePair;
+            } else {
+                return HandType::HighCard;
+            }
+        }
+        HandType::OnePair
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.hand_type() != other.hand_type() {
+            return self
+                .hand_type()
+                .strength()
+                .cmp(&other.hand_type().strength());
+        }
+
+        for (self_card, other_card) in self.cards.iter().zip(other.cards.iter()) {
+            if self_card != other_card {
+                return self_card.cmp(other_card);
+            }
+        }
+        Ordering::Equal
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<Hand>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|line| Hand::from_str(line).map_err(|err| err.within(input, line)))
+            .collect()
+    }
+
+    fn part_one(hands: &Self::Input) -> Answer {
+        total_winnings(hands.to_vec()).into()
+    }
+
+    fn part_two(hands: &Self::Input) -> Answer {
+        let hands = hands.iter().cloned().map(Hand::with_jokers).collect();
+        total_winnings(hands).into()
+    }
+}
+
+fn total_winnings(mut hands: Vec<Hand>) -> usize {
+    hands.sort();
+    hands
+        .iter()
+        .enumerate()
+        .map(|(idx, hand)| {
+            let rank = idx + 1;
+            hand.bid * rank
+        })
+        .sum()
+}
+
+pub fn part_one(file: &str) -> usize {
+    let hands: Vec<Hand> = file
+        .lines()
+        .map(|line| Hand::from_str(line).unwrap_or_else(|err| panic!("{err}")))
+        .collect();
+    total_winnings(hands)
+}
+
+pub fn part_two(file: &str) -> usize {
+    let hands: Vec<Hand> = file
+        .lines()
+        .map(|line| {
+            Hand::from_str(line)
+                .unwrap_or_else(|err| panic!("{err}"))
+                .with_jokers()
+        })
+        .collect();
+    total_winnings(hands)
+}
+
+#[cfg(test)]
+mod day_7_tests {
+    use crate::*;
+
+    #[test]
+    fn test_part_1_example() {
+        assert_eq!(part_one(include_str!("../example")), 6440);
+    }
+
+    #[test]
+    fn test_part_2_example() {
+        assert_eq!(part_two(include_str!("../example")), 5905);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day7::parse("32T3K 765\nT55X5 684").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 4));
+        assert_eq!(err.text, "X");
+        assert!(Day7::parse("32T3 765").is_err());
+        assert!(Day7::parse("32T3K").is_err());
+    }
+
+    #[test]
+    fn test_part_1_has_no_jokers() {
+        // Part one scored hands with part two's joker rules, so it returned 5905 for the
+        // example instead of 6440 and printed part two's answer for both parts.
+        let hand = Hand::from_str("JJ234 1").unwrap();
+        assert_eq!(hand.hand_type(), HandType::OnePair);
+        assert_eq!(hand.with_jokers().hand_type(), HandType::Three);
+        assert!(Card::from('J') > Card::from('T'));
+        assert_ne!(
+            part_one(include_str!("../example")),
+            part_two(include_str!("../example"))
+        );
+    }
+}
@@ -0,0 +1,237 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    str::FromStr,
+};
+
+use rayon::prelude::*;
+
+use aoc_common::{math, Answer, ParseError, Solution};
+
+#[derive(Default, Debug, Clone)]
+pub struct Node {
+    label: String,
+    next_options: [String; 2],
+}
+
+impl FromStr for Node {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (label, options) = s.split_once(" = ").ok_or_else(|| {
+            ParseError::new(Day8::DAY, "expected `<node> = (<left>, <right>)`").at(s, s.trim())
+        })?;
+        let (left, right) = options
+            .strip_prefix('(')
+            .and_then(|options| options.strip_suffix(')'))
+            .and_then(|options| options.split_once(", "))
+            .ok_or_else(|| {
+                ParseError::new(Day8::DAY, "expected `(<left>, <right>)`").at(s, options)
+            })?;
+        let res = Node {
+            label: label.to_string(),
+            next_options: [left.to_string(), right.to_string()],
+        };
+        Ok(res)
+    }
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct Map {
+    order: VecDeque<usize>,
+    nodes: Vec<Node>,
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.trim().split("\n\n");
+        let instructions = lines.next().unwrap_or_default();
+        let order = instructions
+            .char_indices()
+            .map(|(idx, c)| match c {
+                'L' => Ok(0),
+                'R' => Ok(1),
+                _ => Err(
+                    ParseError::new(Day8::DAY, "expected an `L` or `R` instruction")
+                        .at(s, &instructions[idx..idx + c.len_utf8()]),
+                ),
+            })
+            .collect::<Result<VecDeque<usize>, ParseError>>()?;
+        if order.is_empty() {
+            return Err(ParseError::new(Day8::DAY, "missing instructions"));
+        }
+        let network = lines.next().ok_or_else(|| {
+            ParseError::new(Day8::DAY, "missing node network").at(s, instructions)
+        })?;
+        let nodes: Vec<Node> = network
+            .lines()
+            .map(|line| Node::from_str(line).map_err(|err| err.within(s, line)))
+            .collect::<Result<Vec<Node>, ParseError>>()?;
+
+        let labels: HashSet<&str> = nodes.iter().map(|node| node.label.as_str()).collect();
+        for (line, node) in network.lines().zip(&nodes) {
+            for (side, target) in node.next_options.iter().enumerate() {
+                if !labels.contains(target.as_str()) {
+                    // `Node::from_str` accepted the line, so it ends in `(<left>, <right>)`.
+                    let at = match side {
+                        0 => line.find('(').map(|idx| idx + 1),
+                        _ => line.len().checked_sub(target.len() + 1),
+                    }
+                    .map_or(line, |idx| &line[idx..idx + target.len()]);
+                    return Err(ParseError::new(Day8::DAY, "unknown node").at(s, at));
+                }
+            }
+        }
+        let res = Map { order, nodes };
+        Ok(res)
+    }
+}
+
+impl Map {
+    /// Steps from `AAA` to `ZZZ`, or `None` if the network lacks either of them, as part
+    /// two's example does.
+    fn walk_to_end(&mut self) -> Option<usize> {
+        for label in ["AAA", "ZZZ"] {
+            if !self.nodes.iter().any(|node| node.label == label) {
+                return None;
+            }
+        }
+        let mut steps = 0;
+        let mut current = "AAA";
+
+        while current != "ZZZ" {
+            let node: &Node = self
+                .nodes
+                .iter()
+                .find(|node| node.label == current)
+                .unwrap();
+            current = &node.label;
+            steps += 1;
+
+            if let Some(instruction) = self.order.pop_front() {
+                self.order.push_back(instruction);
+                current = &node.next_options[instruction];
+            };
+        }
+        Some(steps)
+    }
+
+    /// Steps until every ghost stands on a `Z` node at once, or the start node whose cycle
+    /// pushes the count past a `u64`.
+    fn walk_to_end_mult(&mut self) -> Result<u64, String> {
+        let current_nodes: Vec<String> = self
+            .nodes
+            .par_iter()
+            .filter_map(|node| {
+                if node.label.ends_with("A") {
+                    return Some(node.label.clone());
+                }
+                None
+            })
+            .collect();
+
+        let mut results: Vec<(&str, u64)> = vec![];
+        for start in current_nodes.iter() {
+            let mut current = start.clone();
+            let mut steps = 0;
+            while !current.ends_with("Z") {
+                let node: &Node = self
+                    .nodes
+                    .iter()
+                    .find(|node| node.label == current)
+                    .unwrap();
+                current = node.label.clone();
+                steps += 1;
+
+                if let Some(instruction) = self.order.pop_front() {
+                    self.order.push_back(instruction);
+                    current = node.next_options[instruction].clone();
+                };
+            }
+            results.push((start, steps));
+        }
+        results.iter().try_fold(1, |acc, (start, steps)| {
+            math::checked_lcm(acc, *steps).ok_or_else(|| start.to_string())
+        })
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Map::from_str(input)
+    }
+
+    fn part_one(map: &Self::Input) -> Answer {
+        walk_to_end(map).into()
+    }
+
+    fn part_two(map: &Self::Input) -> Answer {
+        walk_to_end_mult(map).into()
+    }
+}
+
+fn walk_to_end(map: &Map) -> usize {
+    map.clone()
+        .walk_to_end()
+        .unwrap_or_else(|| panic!("Network has no `AAA` to `ZZZ` route"))
+}
+
+fn walk_to_end_mult(map: &Map) -> u64 {
+    map.clone()
+        .walk_to_end_mult()
+        .unwrap_or_else(|start| panic!("Step count overflows u64 at start node {start}"))
+}
+
+pub fn part_one(file: &str) -> usize {
+    let map = Map::from_str(file).unwrap_or_else(|err| panic!("{err}"));
+    walk_to_end(&map)
+}
+
+pub fn part_two(file: &str) -> u64 {
+    let map = Map::from_str(file).unwrap_or_else(|err| panic!("{err}"));
+    walk_to_end_mult(&map)
+}
+
+#[cfg(test)]
+mod day_8_tests {
+    use crate::*;
+
+    #[test]
+    fn test_part_1_example() {
+        assert_eq!(part_one(include_str!("../example")), 6);
+    }
+
+    #[test]
+    fn test_part_2_example() {
+        assert_eq!(part_two(include_str!("../example-2")), 6);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Map::from_str("LR\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 8, "BBB"));
+        let err = Map::from_str("LXR\n\nZZZ = (ZZZ, ZZZ)").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 2, "X"));
+        let err = Map::from_str("LR\n\nAAA = (ZZZ, ZZ)\nZZZ = (ZZZ, ZZZ)").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 13, "ZZ"));
+        assert_eq!(
+            Map::from_str("\n").unwrap_err().reason,
+            "missing instructions"
+        );
+    }
+
+    #[test]
+    fn test_missing_route() {
+        let mut map = Map::from_str(include_str!("../example-2")).unwrap();
+        assert_eq!(map.walk_to_end(), None);
+        let mut map = Map::from_str(include_str!("../example")).unwrap();
+        assert_eq!(map.walk_to_end(), Some(6));
+    }
+}
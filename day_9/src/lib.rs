@@ -0,0 +1,126 @@
+use std::collections::VecDeque;
+
+use aoc_common::{error::parse_number, Answer, ParseError, Solution};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Vec<isize>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                line.split_whitespace()
+                    .map(|num| parse_number(Day9::DAY, input, num))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part_one(sequences: &Self::Input) -> Answer {
+        next_values(sequences).into()
+    }
+
+    fn part_two(sequences: &Self::Input) -> Answer {
+        previous_values(sequences).into()
+    }
+}
+
+pub fn part_one(file: &str) -> isize {
+    let sequences = Day9::parse(file).unwrap_or_else(|err| panic!("{err}"));
+    next_values(&sequences)
+}
+
+pub fn part_two(file: &str) -> isize {
+    let sequences = Day9::parse(file).unwrap_or_else(|err| panic!("{err}"));
+    previous_values(&sequences)
+}
+
+fn next_values(sequences: &[Vec<isize>]) -> isize {
+    let mut sum = 0;
+    for seq in sequences.iter() {
+        let mut diffs: Vec<Vec<isize>> = vec![seq.clone()];
+        let mut current = seq.clone();
+        while !current.iter().all(|e| *e == 0) {
+            let temp: Vec<isize> = current
+                .iter()
+                .enumerate()
+                .filter_map(|(idx, num)| {
+                    if let Some(v) = current.get(idx + 1) {
+                        return Some(v - num);
+                    }
+                    None
+                })
+                .collect();
+            diffs.push(temp.clone());
+            current = temp;
+        }
+        let mut last = diffs.pop().unwrap();
+        last.push(0);
+        while let Some(mut t) = diffs.pop() {
+            let x = t.last().unwrap();
+            t.push(x + last.last().unwrap());
+            last = t;
+        }
+        sum += last.last().unwrap();
+    }
+    sum
+}
+
+fn previous_values(sequences: &[Vec<isize>]) -> isize {
+    let mut sum = 0;
+    for seq in sequences.iter() {
+        let mut diffs: Vec<Vec<isize>> = vec![seq.clone()];
+        let mut current = seq.clone();
+        while !current.iter().all(|e| *e == 0) {
+            let temp: Vec<isize> = current
+                .iter()
+                .enumerate()
+                .filter_map(|(idx, num)| {
+                    if let Some(v) = current.get(idx + 1) {
+                        return Some(v - num);
+                    }
+                    None
+                })
+                .collect();
+            diffs.push(temp.clone());
+            current = temp;
+        }
+
+        let last = diffs.pop().unwrap();
+        let mut last = VecDeque::from(last);
+        last.push_front(0);
+        while let Some(t) = diffs.pop() {
+            let x = t.first().unwrap();
+            let mut t = VecDeque::from(t.clone());
+            t.push_front(x - last.front().unwrap());
+            last = t;
+        }
+        sum += last.front().unwrap();
+    }
+    sum
+}
+
+#[cfg(test)]
+mod day_9_tests {
+    use crate::*;
+
+    #[test]
+    fn test_part_1_example() {
+        assert_eq!(part_one(include_str!("../example")), 114);
+    }
+
+    #[test]
+    fn test_part_2_example() {
+        assert_eq!(part_two(include_str!("../example")), 2);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day9::parse("0 3 6\n1 3 x6 10").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 5, "x6"));
+    }
+}